@@ -14,9 +14,7 @@ fn main() {
     bme280.init(&mut delay).unwrap();
     loop {
         let measurements = bme280.measure(&mut delay).unwrap();
-        println!("Relative Humidity = {}%", measurements.humidity);
-        println!("Temperature = {} deg C", measurements.temperature);
-        println!("Pressure = {} pascals", measurements.pressure);
+        println!("{measurements}");
         thread::sleep(Duration::from_secs(1));
     }
 }
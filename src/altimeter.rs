@@ -0,0 +1,67 @@
+//! Altimeter calibrated against a known altitude.
+//!
+//! The altitude computed from the pressure depends on the sea level pressure, which changes with
+//! the weather. Setting the altitude of a known location, e.g. a trailhead or a summit, once
+//! back-computes the matching sea level pressure:
+//!
+//! ```ignore
+//! let mut altimeter = Altimeter::new();
+//! let measurements = bme280.measure(&mut delay)?;
+//! altimeter.set_known_altitude(measurements.pressure.unwrap(), 1250.0);
+//! // later on
+//! let altitude = altimeter.altitude_of(&bme280.measure(&mut delay)?);
+//! ```
+
+use crate::Measurements;
+use crate::derived;
+
+/// Converts pressures to altitudes relative to a calibrated sea level pressure
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Altimeter {
+    sea_level_pressure: f32,
+}
+
+impl Default for Altimeter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Altimeter {
+    /// Creates an altimeter using the standard sea level pressure
+    pub fn new() -> Self {
+        Self::with_sea_level_pressure(derived::STANDARD_SEA_LEVEL_PRESSURE)
+    }
+
+    /// Creates an altimeter using the given sea level pressure in pascals, e.g. the local QNH
+    pub fn with_sea_level_pressure(sea_level_pressure: f32) -> Self {
+        Self { sea_level_pressure }
+    }
+
+    /// Calibrates the altimeter with a pressure in pascals measured at a known altitude in
+    /// meters
+    pub fn set_known_altitude(&mut self, pressure: f32, altitude_m: f32) {
+        self.sea_level_pressure = derived::sea_level_pressure(pressure, altitude_m);
+    }
+
+    /// Returns the reference sea level pressure in pascals
+    pub fn sea_level_pressure(&self) -> f32 {
+        self.sea_level_pressure
+    }
+
+    /// Sets the reference sea level pressure in pascals
+    pub fn set_sea_level_pressure(&mut self, sea_level_pressure: f32) {
+        self.sea_level_pressure = sea_level_pressure;
+    }
+
+    /// Computes the altitude in meters from a pressure in pascals
+    pub fn altitude_m(&self, pressure: f32) -> f32 {
+        derived::altitude_m(pressure, self.sea_level_pressure)
+    }
+
+    /// Computes the altitude in meters from a measurement, or `None` if the pressure was skipped
+    pub fn altitude_of<E>(&self, measurements: &Measurements<E>) -> Option<f32> {
+        measurements.altitude_m(self.sea_level_pressure)
+    }
+}
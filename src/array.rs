@@ -0,0 +1,250 @@
+//! Management of several sensors, e.g. an array of BME280s behind an I²C multiplexer or spread
+//! over several buses:
+//!
+//! ```ignore
+//! let mut array = Bme280Array::new([
+//!     BME280::new_primary(bus.acquire_i2c()),
+//!     BME280::new_secondary(bus.acquire_i2c()),
+//! ]);
+//! for (index, result) in array.init_all(&mut delay).iter().enumerate() {
+//!     // ...
+//! }
+//! let measurements = array.measure_all(&mut delay);
+//! ```
+//!
+//! Sensors of different types, e.g. on I²C and SPI, are combined with an enum implementing
+//! [`Sensor`], or with `Box<dyn Bme280Driver>` and the `alloc` feature.
+//!
+//! `AsyncBme280Array` does the same for the async drivers, and overlaps the conversions of
+//! all sensors.
+
+#[cfg(feature = "sync")]
+use embedded_hal::delay::DelayNs;
+#[cfg(feature = "async")]
+use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
+
+use crate::{Error, Measurements};
+
+#[cfg(feature = "sync")]
+/// Blocking driver managed by a [`Bme280Array`]
+pub trait Sensor {
+    /// Error of the underlying bus
+    type BusError;
+
+    /// Initializes the sensor, see `BME280::init()`
+    fn init<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<Self::BusError>>;
+
+    /// Captures and processes a measurement, see `BME280::measure()`
+    fn measure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<Self::BusError>, Error<Self::BusError>>;
+}
+
+#[cfg(feature = "sync")]
+impl<I2C: embedded_hal::i2c::I2c> Sensor for crate::i2c::BME280<I2C> {
+    type BusError = I2C::Error;
+
+    fn init<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<Self::BusError>> {
+        crate::i2c::BME280::init(self, delay)
+    }
+
+    fn measure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<Self::BusError>, Error<Self::BusError>> {
+        crate::i2c::BME280::measure(self, delay)
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<SPI: embedded_hal::spi::SpiDevice> Sensor for crate::spi::BME280<SPI> {
+    type BusError = SPI::Error;
+
+    fn init<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<Self::BusError>> {
+        crate::spi::BME280::init(self, delay)
+    }
+
+    fn measure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<Self::BusError>, Error<Self::BusError>> {
+        crate::spi::BME280::measure(self, delay)
+    }
+}
+
+#[cfg(feature = "sync")]
+/// Outcome of measuring a single sensor of a [`Bme280Array`]
+pub type MeasurementResult<S> =
+    Result<Measurements<<S as Sensor>::BusError>, Error<<S as Sensor>::BusError>>;
+
+#[cfg(feature = "sync")]
+/// Fixed set of `N` sensors, initialized and measured one after the other.
+/// Results are reported per sensor, at the index of the sensor in the array, so a failing
+/// sensor does not hide the measurements of the others.
+#[derive(Debug)]
+pub struct Bme280Array<S, const N: usize> {
+    sensors: [S; N],
+}
+
+#[cfg(feature = "sync")]
+impl<S: Sensor, const N: usize> Bme280Array<S, N> {
+    /// Creates an array from uninitialized sensors
+    pub fn new(sensors: [S; N]) -> Self {
+        Self { sensors }
+    }
+
+    /// Returns the sensors, e.g. to configure them individually
+    pub fn sensors(&mut self) -> &mut [S; N] {
+        &mut self.sensors
+    }
+
+    /// Releases the sensors
+    pub fn into_inner(self) -> [S; N] {
+        self.sensors
+    }
+
+    /// Initializes all sensors
+    pub fn init_all<D: DelayNs>(&mut self, delay: &mut D) -> [Result<(), Error<S::BusError>>; N] {
+        self.sensors.each_mut().map(|sensor| sensor.init(delay))
+    }
+
+    /// Measures all sensors
+    pub fn measure_all<D: DelayNs>(&mut self, delay: &mut D) -> [MeasurementResult<S>; N] {
+        self.sensors.each_mut().map(|sensor| sensor.measure(delay))
+    }
+}
+
+/// Async driver managed by an [`AsyncBme280Array`]
+#[cfg(feature = "async")]
+#[allow(async_fn_in_trait)]
+pub trait AsyncSensor {
+    /// Error of the underlying bus
+    type BusError;
+
+    /// Initializes the sensor, see `AsyncBME280::init()`
+    async fn init<D: AsyncDelayNs>(&mut self, delay: &mut D) -> Result<(), Error<Self::BusError>>;
+
+    /// Starts a forced conversion, see `AsyncBME280::trigger_measurement()`
+    async fn trigger_measurement(&mut self) -> Result<(), Error<Self::BusError>>;
+
+    /// Reads the result of the conversion, see `AsyncBME280::read_measurement()`
+    async fn read_measurement(
+        &mut self,
+    ) -> Result<Measurements<Self::BusError>, Error<Self::BusError>>;
+
+    /// Returns the longest time in microseconds a forced conversion takes
+    fn max_measurement_time_us(&self) -> u32;
+}
+
+macro_rules! impl_async_sensor {
+    ($driver:ty, $bus:ident: $bus_trait:path, $error:ty) => {
+        #[cfg(feature = "async")]
+        impl<$bus: $bus_trait> AsyncSensor for $driver {
+            type BusError = $error;
+
+            async fn init<D: AsyncDelayNs>(
+                &mut self,
+                delay: &mut D,
+            ) -> Result<(), Error<Self::BusError>> {
+                <$driver>::init(self, delay).await
+            }
+
+            async fn trigger_measurement(&mut self) -> Result<(), Error<Self::BusError>> {
+                <$driver>::trigger_measurement(self).await
+            }
+
+            async fn read_measurement(
+                &mut self,
+            ) -> Result<Measurements<Self::BusError>, Error<Self::BusError>> {
+                <$driver>::read_measurement(self).await
+            }
+
+            fn max_measurement_time_us(&self) -> u32 {
+                <$driver>::max_measurement_time_us(self)
+            }
+        }
+    };
+}
+
+impl_async_sensor!(
+    crate::i2c::AsyncBME280<I2C>,
+    I2C: embedded_hal_async::i2c::I2c,
+    I2C::Error
+);
+impl_async_sensor!(
+    crate::spi::AsyncBME280<SPI>,
+    SPI: embedded_hal_async::spi::SpiDevice,
+    SPI::Error
+);
+
+/// Outcome of measuring a single sensor of an [`AsyncBme280Array`]
+#[cfg(feature = "async")]
+pub type AsyncMeasurementResult<S> =
+    Result<Measurements<<S as AsyncSensor>::BusError>, Error<<S as AsyncSensor>::BusError>>;
+
+/// Fixed set of `N` async sensors.
+/// Conversions are started on all sensors before waiting, so measuring the array takes a
+/// single conversion time instead of one per sensor. Results are reported per sensor, at the
+/// index of the sensor in the array.
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct AsyncBme280Array<S, const N: usize> {
+    sensors: [S; N],
+}
+
+#[cfg(feature = "async")]
+impl<S: AsyncSensor, const N: usize> AsyncBme280Array<S, N> {
+    /// Creates an array from uninitialized sensors
+    pub fn new(sensors: [S; N]) -> Self {
+        Self { sensors }
+    }
+
+    /// Returns the sensors, e.g. to configure them individually
+    pub fn sensors(&mut self) -> &mut [S; N] {
+        &mut self.sensors
+    }
+
+    /// Releases the sensors
+    pub fn into_inner(self) -> [S; N] {
+        self.sensors
+    }
+
+    /// Initializes all sensors
+    pub async fn init_all<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> [Result<(), Error<S::BusError>>; N] {
+        let mut results = [const { Ok(()) }; N];
+        for (sensor, result) in self.sensors.iter_mut().zip(&mut results) {
+            *result = sensor.init(delay).await;
+        }
+        results
+    }
+
+    /// Measures all sensors, overlapping their conversions
+    pub async fn measure_all<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> [AsyncMeasurementResult<S>; N] {
+        let mut triggered = [const { Ok(()) }; N];
+        let mut conversion_us = 0;
+        for (sensor, result) in self.sensors.iter_mut().zip(&mut triggered) {
+            *result = sensor.trigger_measurement().await;
+            conversion_us = conversion_us.max(sensor.max_measurement_time_us());
+        }
+
+        delay.delay_us(conversion_us).await;
+
+        let mut results = triggered.map(|result| result.map(|()| None));
+        for (sensor, result) in self.sensors.iter_mut().zip(&mut results) {
+            if let Ok(measurements) = result {
+                *measurements = Some(sensor.read_measurement().await);
+            }
+        }
+        // every triggered sensor has been read, so no measurement is left out
+        results.map(|result| {
+            result.and_then(|measurements| measurements.unwrap_or(Err(Error::InvalidData)))
+        })
+    }
+}
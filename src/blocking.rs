@@ -0,0 +1,133 @@
+//! Blocking adapter over the async drivers, so host tools can reuse async transports, e.g. USB
+//! bridges with async drivers, from blocking code:
+//!
+//! ```ignore
+//! let mut bme280 = Blocking::new(AsyncBME280::new_primary(async_i2c));
+//! bme280.init(&mut delay)?;
+//! let measurements = bme280.measure(&mut delay)?;
+//! ```
+//!
+//! The futures are driven by an [`Executor`], which defaults to [`ParkExecutor`]. Transports
+//! that need a specific runtime, e.g. tokio, plug in its `block_on` with their own executor.
+
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use embedded_hal::delay::DelayNs;
+
+use crate::forward::forward_methods;
+
+/// Runs futures to completion on the current thread
+pub trait Executor {
+    /// Polls `future` until it completes and returns its output
+    fn block_on<F: Future>(&mut self, future: F) -> F::Output;
+}
+
+/// Executor parking the current thread until the future is woken
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ParkExecutor;
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+impl Executor for ParkExecutor {
+    fn block_on<F: Future>(&mut self, future: F) -> F::Output {
+        let mut future = pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut context = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+            thread::park();
+        }
+    }
+}
+
+/// Blocking delay usable by the async drivers
+struct BlockingDelay<'a, D>(&'a mut D);
+
+impl<D: DelayNs> embedded_hal_async::delay::DelayNs for BlockingDelay<'_, D> {
+    async fn delay_ns(&mut self, ns: u32) {
+        self.0.delay_ns(ns);
+    }
+}
+
+/// Async driver exposing the blocking API.
+/// The methods not covered here are reachable through [`Self::inner`] and [`Self::executor`].
+#[derive(Debug, Default)]
+pub struct Blocking<B, X = ParkExecutor> {
+    bme280: B,
+    executor: X,
+}
+
+impl<B> Blocking<B> {
+    /// Wraps an async driver, driving its futures with a [`ParkExecutor`]
+    pub fn new(bme280: B) -> Self {
+        Self::with_executor(bme280, ParkExecutor)
+    }
+}
+
+impl<B, X> Blocking<B, X> {
+    /// Wraps an async driver, driving its futures with the given executor
+    pub fn with_executor(bme280: B, executor: X) -> Self {
+        Self { bme280, executor }
+    }
+
+    /// Returns the wrapped driver
+    pub fn inner(&mut self) -> &mut B {
+        &mut self.bme280
+    }
+
+    /// Returns the executor
+    pub fn executor(&mut self) -> &mut X {
+        &mut self.executor
+    }
+
+    /// Releases the wrapped driver and the executor
+    pub fn into_inner(self) -> (B, X) {
+        (self.bme280, self.executor)
+    }
+}
+
+/// Drives the futures of the driver methods with the executor
+macro_rules! blocking {
+    ($this:ident, $delay:ident, $method:ident($($arg:expr),*)) => {
+        $this
+            .executor
+            .block_on($this.bme280.$method(&mut BlockingDelay($delay) $(, $arg)*))
+    };
+    ($this:ident, $method:ident($($arg:expr),*)) => {
+        $this.executor.block_on($this.bme280.$method($($arg),*))
+    };
+}
+
+macro_rules! impl_blocking {
+    ($driver:ty, $bus:ident: $bus_trait:path, $error:ty) => {
+        forward_methods! {
+            impl[$bus: $bus_trait, X: Executor] Blocking<$driver, X> where [];
+            receiver: [&mut], async: [], delay: [D: DelayNs], format: [], error: $error,
+            call: blocking;
+            setup, measure, control, read
+        }
+    };
+}
+
+impl_blocking!(
+    crate::i2c::AsyncBME280<I2C>,
+    I2C: embedded_hal_async::i2c::I2c,
+    I2C::Error
+);
+impl_blocking!(
+    crate::spi::AsyncBME280<SPI>,
+    SPI: embedded_hal_async::spi::SpiDevice,
+    SPI::Error
+);
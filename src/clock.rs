@@ -0,0 +1,100 @@
+//! Timestamped measurements.
+//!
+//! A [`Clock`] provides the timestamps, so the same code stamps readings on any time base:
+//!
+//! ```ignore
+//! // std::time, with the `with_std` feature
+//! let sample = bme280.measure_stamped(&mut delay, &mut StdClock)?;
+//! // embassy-time, with the `embassy-time` feature
+//! let sample = bme280.measure_stamped(&mut delay, &mut EmbassyClock).await?;
+//! // any other time base, e.g. an RTIC monotonic, through a closure
+//! let sample = bme280.measure_stamped(&mut delay, &mut || Mono::now())?;
+//! ```
+
+/// Source of timestamps
+pub trait Clock {
+    /// Point in time returned by the clock
+    type Instant: Copy;
+
+    /// Returns the current time
+    fn now(&mut self) -> Self::Instant;
+}
+
+/// Any closure returning a timestamp is a clock.
+impl<I: Copy, F: FnMut() -> I> Clock for F {
+    type Instant = I;
+
+    fn now(&mut self) -> I {
+        self()
+    }
+}
+
+/// Clock reading [`std::time::Instant`]
+#[cfg(feature = "with_std")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StdClock;
+
+#[cfg(feature = "with_std")]
+impl Clock for StdClock {
+    type Instant = std::time::Instant;
+
+    fn now(&mut self) -> Self::Instant {
+        std::time::Instant::now()
+    }
+}
+
+/// Clock reading [`embassy_time::Instant`]
+#[cfg(feature = "embassy-time")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EmbassyClock;
+
+#[cfg(feature = "embassy-time")]
+impl Clock for EmbassyClock {
+    type Instant = embassy_time::Instant;
+
+    fn now(&mut self) -> Self::Instant {
+        embassy_time::Instant::now()
+    }
+}
+
+/// Float measurement paired with the time of `C` it was read from the sensor
+#[cfg(feature = "float")]
+pub type StampedMeasurements<C, E> = Sample<<C as Clock>::Instant, crate::Measurements<E>>;
+
+/// Measurement paired with the time it was read from the sensor and its sequence number
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Sample<I, M> {
+    /// time the measurement was read, after the end of the conversion
+    pub timestamp: I,
+    /// sequence number of the measurement, see `BME280::sequence()`
+    pub sequence: u32,
+    /// the measurement
+    pub measurements: M,
+}
+
+impl<I, M> Sample<I, M> {
+    /// Pairs a measurement with its timestamp and sequence number
+    pub fn new(timestamp: I, sequence: u32, measurements: M) -> Self {
+        Self {
+            timestamp,
+            sequence,
+            measurements,
+        }
+    }
+
+    /// Converts the measurement, keeping the timestamp and sequence number
+    pub fn map<N>(self, f: impl FnOnce(M) -> N) -> Sample<I, N> {
+        Sample::new(self.timestamp, self.sequence, f(self.measurements))
+    }
+
+    /// Returns the number of measurements missed between `previous` and this sample, e.g. lost
+    /// on a lossy link, or `None` if this sample is not newer than `previous`, e.g. a duplicate.
+    pub fn missed_since<P, N>(&self, previous: &Sample<P, N>) -> Option<u32> {
+        match self.sequence.wrapping_sub(previous.sequence) {
+            0 => None,
+            delta if delta > u32::MAX / 2 => None,
+            delta => Some(delta - 1),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Blocking driver shared between the main loop and interrupt handlers, guarded by a
+//! [`critical_section`]:
+//!
+//! ```ignore
+//! static BME280: StaticCell<CriticalSectionBme280<BME280<I2c>>> = StaticCell::new();
+//! let bme280 = BME280.init(CriticalSectionBme280::new(BME280::new_primary(i2c)));
+//! bme280.lock(|bme280| bme280.init(&mut delay))?;
+//!
+//! // in a timer interrupt
+//! bme280.trigger_measurement()?;
+//! // in a later interrupt, once the conversion time has elapsed
+//! let measurements = bme280.read_measurement()?;
+//! ```
+//!
+//! Interrupts stay disabled for the duration of each call, so the methods of the wrapper are
+//! limited to calls that do not wait. Waiting for a conversion with `measure()` inside
+//! [`Share::lock`] is possible, but blocks all interrupts for the whole conversion time.
+//! [`Share::split`] hands a control handle and a read handle to different contexts.
+
+use core::cell::RefCell;
+
+use critical_section::Mutex;
+
+use crate::forward::forward_methods;
+use crate::split::Share;
+
+/// Blocking driver behind a critical section mutex, usable through a shared reference from any
+/// context
+#[derive(Debug)]
+pub struct CriticalSectionBme280<B> {
+    bme280: Mutex<RefCell<B>>,
+}
+
+impl<B> CriticalSectionBme280<B> {
+    /// Wraps a blocking driver
+    pub const fn new(bme280: B) -> Self {
+        Self {
+            bme280: Mutex::new(RefCell::new(bme280)),
+        }
+    }
+
+    /// Replaces the wrapped driver, returning the previous one
+    pub fn replace(&self, bme280: B) -> B {
+        self.lock(|current| core::mem::replace(current, bme280))
+    }
+
+    /// Releases the wrapped driver
+    pub fn into_inner(self) -> B {
+        self.bme280.into_inner().into_inner()
+    }
+}
+
+/// Panics if the driver is locked again from within [`Share::lock`].
+impl<B> Share<B> for CriticalSectionBme280<B> {
+    fn lock<R>(&self, f: impl FnOnce(&mut B) -> R) -> R {
+        critical_section::with(|cs| f(&mut self.bme280.borrow_ref_mut(cs)))
+    }
+}
+
+/// Runs the driver methods inside a critical section
+macro_rules! critical {
+    ($this:ident, $method:ident($($arg:expr),*)) => {
+        $this.lock(|bme280| bme280.$method($($arg),*))
+    };
+}
+
+macro_rules! impl_critical {
+    ($driver:ty, $bus:ident: $bus_trait:path, $error:ty) => {
+        forward_methods! {
+            impl[$bus: $bus_trait] CriticalSectionBme280<$driver> where [];
+            receiver: [&], async: [], delay: [], format: [], error: $error, call: critical;
+            control, read
+        }
+    };
+}
+
+impl_critical!(
+    crate::i2c::BME280<I2C>,
+    I2C: embedded_hal::i2c::I2c,
+    I2C::Error
+);
+impl_critical!(
+    crate::spi::BME280<SPI>,
+    SPI: embedded_hal::spi::SpiDevice,
+    SPI::Error
+);
@@ -0,0 +1,103 @@
+//! CSV records of measurements, for data loggers writing to an SD card or a UART.
+//!
+//! Records have the fields of [`HEADER`] in that order: the temperature in degrees celsius, the
+//! pressure in pascals and the relative humidity in percent. Skipped values are left empty.
+//! Lines end with `\r\n` as per RFC 4180.
+//!
+//! ```ignore
+//! csv::write_header(&mut uart)?;
+//! loop {
+//!     bme280.measure(&mut delay)?.write_csv(&mut uart)?;
+//! }
+//! ```
+
+use core::fmt::{Result, Write};
+
+#[cfg(feature = "float")]
+use crate::Measurements;
+#[cfg(feature = "double-precision")]
+use crate::MeasurementsDouble;
+#[cfg(feature = "fixed")]
+use crate::MeasurementsFixed;
+use crate::MeasurementsFixedRaw;
+use crate::decimal::Decimal;
+
+/// Names of the fields of a record
+pub const HEADER: &str = "temperature_c,pressure_pa,humidity_pct";
+
+/// Writes the header line
+pub fn write_header<W: Write>(w: &mut W) -> Result {
+    w.write_str(HEADER)?;
+    w.write_str("\r\n")
+}
+
+/// Measurements that can be written as a CSV record
+pub trait WriteCsv {
+    /// Writes the measurement as a CSV line
+    fn write_csv<W: Write>(&self, w: &mut W) -> Result;
+}
+
+#[cfg(feature = "float")]
+impl<E> WriteCsv for Measurements<E> {
+    fn write_csv<W: Write>(&self, w: &mut W) -> Result {
+        write!(w, "{:.2},", self.temperature)?;
+        if let Some(pressure) = self.pressure {
+            write!(w, "{pressure:.2}")?;
+        }
+        w.write_char(',')?;
+        if let Some(humidity) = self.humidity {
+            write!(w, "{humidity:.3}")?;
+        }
+        w.write_str("\r\n")
+    }
+}
+
+#[cfg(feature = "double-precision")]
+impl<E> WriteCsv for MeasurementsDouble<E> {
+    fn write_csv<W: Write>(&self, w: &mut W) -> Result {
+        write!(w, "{:.2},", self.temperature)?;
+        if let Some(pressure) = self.pressure {
+            write!(w, "{pressure:.2}")?;
+        }
+        w.write_char(',')?;
+        if let Some(humidity) = self.humidity {
+            write!(w, "{humidity:.3}")?;
+        }
+        w.write_str("\r\n")
+    }
+}
+
+impl<E> WriteCsv for MeasurementsFixedRaw<E> {
+    fn write_csv<W: Write>(&self, w: &mut W) -> Result {
+        write_fixed(w, self.temperature, self.pressure, self.humidity)
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl<E> WriteCsv for MeasurementsFixed<E> {
+    fn write_csv<W: Write>(&self, w: &mut W) -> Result {
+        write_fixed(
+            w,
+            self.temperature,
+            self.pressure.map(|pressure| pressure.to_bits()),
+            self.humidity.map(|humidity| humidity.to_bits()),
+        )
+    }
+}
+
+fn write_fixed<W: Write>(
+    w: &mut W,
+    temperature: i32,
+    pressure: Option<u32>,
+    humidity: Option<u32>,
+) -> Result {
+    write!(w, "{},", Decimal::from_hundredths(temperature))?;
+    if let Some(pressure) = pressure {
+        write!(w, "{}", Decimal::from_fixed(i64::from(pressure), 8, 2))?;
+    }
+    w.write_char(',')?;
+    if let Some(humidity) = humidity {
+        write!(w, "{}", Decimal::from_fixed(i64::from(humidity), 10, 3))?;
+    }
+    w.write_str("\r\n")
+}
@@ -0,0 +1,150 @@
+//! Decimal formatting of fixed-point values with integer math only.
+
+use core::fmt;
+
+/// Largest supported number of decimals
+const MAX_DECIMALS: u8 = 9;
+
+/// Fixed-point number that formats as a decimal string without floating point math, e.g. for
+/// printing measurements over defmt or a UART on cores without an FPU.
+/// Negative numbers are formatted with a leading `-`, and the fractional part is zero padded to
+/// the number of decimals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decimal {
+    /// value multiplied by 10^decimals
+    scaled: i64,
+    decimals: u8,
+}
+
+impl Decimal {
+    /// Creates a decimal number equal to `scaled / 10^decimals`.
+    /// Numbers with more than 9 decimals are rounded to 9 decimals.
+    pub fn new(scaled: i64, decimals: u8) -> Self {
+        Self { scaled, decimals }.rounded(MAX_DECIMALS)
+    }
+
+    /// Creates a decimal number from a value in hundredths, such as the temperature of
+    /// [`crate::MeasurementsFixedRaw`]
+    pub fn from_hundredths(value: i32) -> Self {
+        Self::new(i64::from(value), 2)
+    }
+
+    /// Creates a decimal number from the bits of a binary fixed-point value with `frac_bits`
+    /// fractional bits, e.g. 8 for Q24.8, rounded to the given number of decimals.
+    pub fn from_fixed(bits: i64, frac_bits: u32, decimals: u8) -> Self {
+        let decimals = decimals.min(MAX_DECIMALS);
+        let scaled = i128::from(bits) * i128::from(10i64.pow(u32::from(decimals)));
+        let half = (1i128 << frac_bits) / 2;
+        let scaled = if scaled < 0 {
+            -((half - scaled) >> frac_bits)
+        } else {
+            (scaled + half) >> frac_bits
+        };
+        Self {
+            scaled: scaled as i64,
+            decimals,
+        }
+    }
+
+    /// Creates a decimal number from a float, rounded to the given number of decimals
+    #[cfg(all(feature = "float", feature = "ufmt"))]
+    pub(crate) fn from_f64(value: f64, decimals: u8) -> Self {
+        let decimals = decimals.min(MAX_DECIMALS);
+        let scaled = value * 10i64.pow(u32::from(decimals)) as f64;
+        let scaled = if scaled < 0.0 {
+            scaled - 0.5
+        } else {
+            scaled + 0.5
+        };
+        Self {
+            scaled: scaled as i64,
+            decimals,
+        }
+    }
+
+    /// Rounds the number to at most the given number of decimals, with halves rounded away from
+    /// zero
+    pub fn rounded(self, decimals: u8) -> Self {
+        if decimals >= self.decimals {
+            return self;
+        }
+        let Some(divisor) = 10i64.checked_pow(u32::from(self.decimals - decimals)) else {
+            return Self {
+                scaled: 0,
+                decimals,
+            };
+        };
+        let half = divisor / 2;
+        let scaled = if self.scaled < 0 {
+            (self.scaled - half) / divisor
+        } else {
+            (self.scaled + half) / divisor
+        };
+        Self { scaled, decimals }
+    }
+
+    /// Divides the number by 10^exponent without loss, e.g. to convert pascals to hectopascals
+    pub(crate) fn shifted(self, exponent: u8) -> Self {
+        Self {
+            scaled: self.scaled,
+            decimals: self.decimals.saturating_add(exponent),
+        }
+        .rounded(MAX_DECIMALS)
+    }
+
+    /// Returns the sign, integer part, and fractional part of the number
+    fn parts(&self) -> (&'static str, u64, u64) {
+        let sign = if self.scaled < 0 { "-" } else { "" };
+        let divisor = 10u64.pow(u32::from(self.decimals));
+        let value = self.scaled.unsigned_abs();
+        (sign, value / divisor, value % divisor)
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (sign, integer, fraction) = self.parts();
+        if self.decimals == 0 {
+            write!(f, "{sign}{integer}")
+        } else {
+            let width = usize::from(self.decimals);
+            write!(f, "{sign}{integer}.{fraction:0width$}")
+        }
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for Decimal {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        let (sign, integer, fraction) = self.parts();
+        ufmt::uwrite!(f, "{}{}", sign, integer)?;
+        if self.decimals > 0 {
+            f.write_str(".")?;
+            let mut divisor = 10u64.pow(u32::from(self.decimals));
+            while divisor > 1 {
+                divisor /= 10;
+                ufmt::uwrite!(f, "{}", fraction / divisor % 10)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Decimal {
+    fn format(&self, f: defmt::Formatter) {
+        let (sign, integer, fraction) = self.parts();
+        defmt::write!(f, "{=str}{=u64}", sign, integer);
+        if self.decimals > 0 {
+            defmt::write!(f, ".");
+            let mut divisor = 10u64.pow(u32::from(self.decimals));
+            while divisor > 1 {
+                divisor /= 10;
+                defmt::write!(f, "{=u64}", fraction / divisor % 10);
+            }
+        }
+    }
+}
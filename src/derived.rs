@@ -0,0 +1,230 @@
+//! Values derived from compensated measurements.
+
+use crate::math;
+use crate::{BME280_HUMIDITY_MAX_FIXED, BME280_TEMP_MAX_FIXED, BME280_TEMP_MIN_FIXED};
+
+/// Standard atmosphere pressure at sea level in pascals
+#[cfg(feature = "float")]
+pub const STANDARD_SEA_LEVEL_PRESSURE: f32 = 101325.0;
+
+/// Computes the altitude in meters from the pressure in pascals, using the barometric formula of
+/// the international standard atmosphere.
+/// `sea_level_pressure` is the reference pressure in pascals, e.g. [`STANDARD_SEA_LEVEL_PRESSURE`]
+/// or the local QNH for a more accurate altitude.
+#[cfg(feature = "float")]
+pub fn altitude_m(pressure: f32, sea_level_pressure: f32) -> f32 {
+    44330.0 * (1.0 - math::powf(pressure / sea_level_pressure, 1.0 / 5.255))
+}
+
+/// Reduces the station pressure in pascals to the equivalent sea level pressure (QNH) for a
+/// station at `altitude_m` meters above sea level.
+/// This is the inverse of [`altitude_m`].
+#[cfg(feature = "float")]
+pub fn sea_level_pressure(pressure: f32, altitude_m: f32) -> f32 {
+    pressure / math::powf(1.0 - altitude_m / 44330.0, 5.255)
+}
+
+/// Lowest altitude accepted by [`sea_level_pressure_fixed`]
+const MIN_ALTITUDE_FIXED: i32 = -500;
+/// Highest altitude accepted by [`sea_level_pressure_fixed`], the top of the troposphere
+const MAX_ALTITUDE_FIXED: i32 = 11000;
+
+/// Integer version of [`sea_level_pressure`], for pressures in pascals in Q24.8 format.
+/// The altitude is saturated to the -500 m to 11000 m range.
+pub fn sea_level_pressure_fixed(pressure: u32, altitude_m: i32) -> u32 {
+    // (1 - h / 44330) ^ -5.255 = exp(-5.255 * ln(1 - h / 44330))
+    let altitude = i64::from(altitude_m.clamp(MIN_ALTITUDE_FIXED, MAX_ALTITUDE_FIXED));
+    let base = math::ONE_Q30 - (altitude << 30) / 44330;
+    let factor = math::exp_q30(-math::ln_q30(base) * 5255 / 1000);
+
+    ((i64::from(pressure) * factor) >> 30).clamp(0, i64::from(u32::MAX)) as u32
+}
+
+/// Magnus formula coefficient b over water (Sonntag 1990)
+#[cfg(feature = "float")]
+const MAGNUS_B: f32 = 17.62;
+/// Magnus formula coefficient c over water in degrees celsius (Sonntag 1990)
+#[cfg(feature = "float")]
+const MAGNUS_C: f32 = 243.12;
+
+/// Computes the dew point in degrees celsius from the temperature in degrees celsius and the
+/// percent relative humidity, using the Magnus formula.
+/// The humidity must be above 0 %. The formula is accurate to about 0.35 deg C between
+/// -45 deg C and 60 deg C.
+#[cfg(feature = "float")]
+pub fn dew_point(temperature: f32, humidity: f32) -> f32 {
+    let gamma = math::lnf(humidity / 100.0) + MAGNUS_B * temperature / (MAGNUS_C + temperature);
+    MAGNUS_C * gamma / (MAGNUS_B - gamma)
+}
+
+/// Magnus formula coefficient b over ice (Sonntag 1990)
+#[cfg(feature = "float")]
+const MAGNUS_B_ICE: f32 = 22.46;
+/// Magnus formula coefficient c over ice in degrees celsius (Sonntag 1990)
+#[cfg(feature = "float")]
+const MAGNUS_C_ICE: f32 = 272.62;
+
+/// Computes the frost point in degrees celsius from the temperature in degrees celsius and the
+/// percent relative humidity, using the Magnus formula over ice.
+/// Below 0 deg C the frost point is higher than the dew point, so ice forms before dew would.
+/// When the frost point is above 0 deg C, water condenses as liquid and the dew point is returned
+/// instead. The humidity must be above 0 %.
+#[cfg(feature = "float")]
+pub fn frost_point(temperature: f32, humidity: f32) -> f32 {
+    let gamma = math::lnf(humidity / 100.0) + MAGNUS_B * temperature / (MAGNUS_C + temperature);
+    let frost_point = MAGNUS_C_ICE * gamma / (MAGNUS_B_ICE - gamma);
+    if frost_point > 0.0 {
+        dew_point(temperature, humidity)
+    } else {
+        frost_point
+    }
+}
+
+/// Integer version of [`dew_point`], for the temperature in hundredths of degrees celsius and
+/// the percent relative humidity in Q22.10 format. The result is in hundredths of degrees
+/// celsius.
+/// The temperature is saturated to the -40 deg C to 85 deg C operating range of the sensor and the
+/// humidity to 100 %. A humidity of 0 % saturates to a dew point far below the temperature
+/// instead of failing.
+pub fn dew_point_fixed(temperature: i32, humidity: u32) -> i32 {
+    let temperature = i64::from(temperature.clamp(BME280_TEMP_MIN_FIXED, BME280_TEMP_MAX_FIXED));
+    let humidity = (i64::from(humidity.min(BME280_HUMIDITY_MAX_FIXED)) << 20) / 100;
+
+    // Magnus coefficients scaled by 100
+    let gamma =
+        math::ln_q30(humidity) + ((1762 * temperature) << 30) / (100 * (24312 + temperature));
+    (24312 * gamma / ((1762 << 30) / 100 - gamma)) as i32
+}
+
+/// Specific gas constant of water vapor in J/(kg K)
+#[cfg(feature = "float")]
+const WATER_VAPOR_GAS_CONSTANT: f32 = 461.5;
+/// 0 deg C in kelvin
+#[cfg(feature = "float")]
+const ZERO_CELSIUS: f32 = 273.15;
+
+/// Computes the saturation vapor pressure over water in pascals at the given temperature in
+/// degrees celsius, using the Magnus formula.
+#[cfg(feature = "float")]
+pub fn saturation_vapor_pressure(temperature: f32) -> f32 {
+    611.2 * math::expf(MAGNUS_B * temperature / (MAGNUS_C + temperature))
+}
+
+/// Computes the vapor pressure deficit in kilopascals from the temperature in degrees celsius
+/// and the percent relative humidity.
+#[cfg(feature = "float")]
+pub fn vapor_pressure_deficit(temperature: f32, humidity: f32) -> f32 {
+    saturation_vapor_pressure(temperature) * (100.0 - humidity.clamp(0.0, 100.0)) / 100_000.0
+}
+
+/// Integer version of [`vapor_pressure_deficit`], for the temperature in hundredths of degrees
+/// celsius and the percent relative humidity in Q22.10 format. The result is in pascals, i.e.
+/// thousandths of kilopascals. The temperature is saturated to the operating range of the sensor.
+pub fn vapor_pressure_deficit_fixed(temperature: i32, humidity: u32) -> u32 {
+    let temperature = i64::from(temperature.clamp(BME280_TEMP_MIN_FIXED, BME280_TEMP_MAX_FIXED));
+    let exponent = ((1762 * temperature) << 30) / (100 * (24312 + temperature));
+    // saturation vapor pressure in pascals in Q10 format
+    let saturation = (6112 * math::exp_q30(exponent) / 10) >> 20;
+    let dryness = i64::from(BME280_HUMIDITY_MAX_FIXED.saturating_sub(humidity));
+    ((saturation * dryness / i64::from(BME280_HUMIDITY_MAX_FIXED)) >> 10) as u32
+}
+
+/// Enhancement factor of the saturation vapor pressure in moist air at the given pressure in
+/// pascals (WMO guide to instruments, annex 4.B)
+#[cfg(feature = "float")]
+fn enhancement_factor(pressure: f32) -> f32 {
+    let pressure = pressure / 100.0;
+    1.0016 + 3.15e-6 * pressure - 0.074 / pressure
+}
+
+/// Computes the absolute humidity in grams per cubic meter from the temperature in degrees
+/// celsius, the percent relative humidity and the pressure in pascals.
+#[cfg(feature = "float")]
+pub fn absolute_humidity(temperature: f32, humidity: f32, pressure: f32) -> f32 {
+    let vapor_pressure = vapor_pressure(temperature, humidity, pressure);
+    1000.0 * vapor_pressure / (WATER_VAPOR_GAS_CONSTANT * (temperature + ZERO_CELSIUS))
+}
+
+/// Vapor pressure in pascals of moist air
+#[cfg(feature = "float")]
+fn vapor_pressure(temperature: f32, humidity: f32, pressure: f32) -> f32 {
+    enhancement_factor(pressure) * saturation_vapor_pressure(temperature) * humidity / 100.0
+}
+
+/// Computes the wet-bulb temperature in degrees celsius from the temperature in degrees celsius,
+/// the percent relative humidity and the pressure in pascals.
+/// The psychrometer equation for a ventilated psychrometer is solved numerically, the result
+/// lies between the dew point and the air temperature.
+#[cfg(feature = "float")]
+pub fn wet_bulb_temperature(temperature: f32, humidity: f32, pressure: f32) -> f32 {
+    let vapor_pressure = vapor_pressure(temperature, humidity, pressure);
+
+    // The psychrometric vapor pressure grows monotonically with the wet-bulb temperature
+    let mut low = dew_point(temperature, humidity.max(0.1)).min(temperature) - 1.0;
+    let mut high = temperature;
+    for _ in 0..32 {
+        let wet_bulb = (low + high) / 2.0;
+        let psychrometer_constant = 6.53e-4 * (1.0 + 0.000944 * wet_bulb);
+        let estimate = enhancement_factor(pressure) * saturation_vapor_pressure(wet_bulb)
+            - psychrometer_constant * pressure * (temperature - wet_bulb);
+        if estimate > vapor_pressure {
+            high = wet_bulb;
+        } else {
+            low = wet_bulb;
+        }
+    }
+    (low + high) / 2.0
+}
+
+/// Computes the specific enthalpy of moist air in kilojoules per kilogram of dry air from the
+/// temperature in degrees celsius, the percent relative humidity and the pressure in pascals.
+#[cfg(feature = "float")]
+pub fn enthalpy(temperature: f32, humidity: f32, pressure: f32) -> f32 {
+    let vapor_pressure = vapor_pressure(temperature, humidity, pressure);
+    let mixing_ratio = 0.622 * vapor_pressure / (pressure - vapor_pressure);
+    1.006 * temperature + mixing_ratio * (2501.0 + 1.86 * temperature)
+}
+
+/// Computes the heat index in degrees celsius from the temperature in degrees celsius and the
+/// percent relative humidity, using the algorithm of the US National Weather Service.
+/// The Rothfusz regression is used from about 26.7 deg C (80 F) upwards, including the
+/// adjustments for very dry and very humid air. Below that, Steadman's simple formula is used,
+/// which stays within about 1 deg C of the air temperature. The humidity is clamped to 0 % to
+/// 100 %. The regression is not meaningful above about 50 deg C.
+#[cfg(feature = "float")]
+pub fn heat_index(temperature: f32, humidity: f32) -> f32 {
+    let t = temperature * 1.8 + 32.0;
+    let rh = humidity.clamp(0.0, 100.0);
+
+    let simple = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094);
+    let heat_index = if (simple + t) / 2.0 < 80.0 {
+        simple
+    } else {
+        let mut hi = -42.379 + 2.049_015_2 * t + 10.143_331 * rh
+            - 0.224_755_4 * t * rh
+            - 0.006_837_83 * t * t
+            - 0.054_817_17 * rh * rh
+            + 0.001_228_74 * t * t * rh
+            + 0.000_852_82 * t * rh * rh
+            - 0.000_001_99 * t * t * rh * rh;
+        if rh < 13.0 && (80.0..=112.0).contains(&t) {
+            hi -= (13.0 - rh) / 4.0 * math::sqrtf((17.0 - (t - 95.0).abs()) / 17.0);
+        } else if rh > 85.0 && (80.0..=87.0).contains(&t) {
+            hi += (rh - 85.0) / 10.0 * ((87.0 - t) / 5.0);
+        }
+        hi
+    };
+
+    (heat_index - 32.0) / 1.8
+}
+
+/// Computes the humidex in degrees celsius from the temperature in degrees celsius and the
+/// percent relative humidity, as defined by Environment Canada.
+/// The humidex saturates at the air temperature when the vapor pressure is below 10 hPa, i.e.
+/// in dry or cold air where the index is not meaningful. Environment Canada only reports it from
+/// 20 deg C upwards.
+#[cfg(feature = "float")]
+pub fn humidex(temperature: f32, humidity: f32) -> f32 {
+    let vapor_pressure = saturation_vapor_pressure(temperature) * humidity / 10000.0;
+    temperature + 0.5555 * (vapor_pressure - 10.0).max(0.0)
+}
@@ -0,0 +1,105 @@
+//! Adapters for I²C buses and delays implementing the embedded-hal 0.2 traits, for board
+//! support crates that have not moved to embedded-hal 1.0 yet:
+//!
+//! ```ignore
+//! use bme280::eh02::Compat;
+//! use bme280::i2c::BME280;
+//!
+//! let mut bme280 = BME280::new_primary(Compat::new(i2c));
+//! bme280.init(&mut Compat::new(delay))?;
+//! ```
+//!
+//! SPI devices need a chip select pin in embedded-hal 1.0; use `embedded-hal-compat` and
+//! `embedded-hal-bus` to build an `SpiDevice` from an embedded-hal 0.2 bus and pin.
+
+use embedded_hal::i2c::{ErrorKind, ErrorType, I2c, Operation, SevenBitAddress};
+use embedded_hal_0_2::blocking::delay::DelayUs;
+use embedded_hal_0_2::blocking::i2c::{Read, Write, WriteRead};
+
+/// Wraps an embedded-hal 0.2 I²C bus or delay to implement the embedded-hal 1.0 traits
+#[derive(Debug, Default)]
+pub struct Compat<T> {
+    inner: T,
+}
+
+impl<T> Compat<T> {
+    /// Wraps an embedded-hal 0.2 I²C bus or delay
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the wrapped bus or delay
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+/// Error of an embedded-hal 0.2 I²C bus
+#[derive(Debug, Clone, Copy)]
+pub struct CompatError<E>(pub E);
+
+impl<E: core::fmt::Debug> embedded_hal::i2c::Error for CompatError<E> {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+}
+
+impl<T, E> ErrorType for Compat<T>
+where
+    T: Write<Error = E> + WriteRead<Error = E> + Read<Error = E>,
+    E: core::fmt::Debug,
+{
+    type Error = CompatError<E>;
+}
+
+/// Transactions are split into separate reads and writes, without repeated starts between them,
+/// as embedded-hal 0.2 buses do not all support transactions. The driver itself only uses
+/// [`I2c::write`] and [`I2c::write_read`], which map directly to embedded-hal 0.2.
+impl<T, E> I2c<SevenBitAddress> for Compat<T>
+where
+    T: Write<Error = E> + WriteRead<Error = E> + Read<Error = E>,
+    E: core::fmt::Debug,
+{
+    fn read(&mut self, address: SevenBitAddress, read: &mut [u8]) -> Result<(), Self::Error> {
+        self.inner.read(address, read).map_err(CompatError)
+    }
+
+    fn write(&mut self, address: SevenBitAddress, write: &[u8]) -> Result<(), Self::Error> {
+        self.inner.write(address, write).map_err(CompatError)
+    }
+
+    fn write_read(
+        &mut self,
+        address: SevenBitAddress,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.inner
+            .write_read(address, write, read)
+            .map_err(CompatError)
+    }
+
+    fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        for operation in operations {
+            match operation {
+                Operation::Read(read) => self.read(address, read)?,
+                Operation::Write(write) => I2c::write(self, address, write)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: DelayUs<u32>> embedded_hal::delay::DelayNs for Compat<T> {
+    fn delay_ns(&mut self, ns: u32) {
+        self.inner.delay_us(ns.div_ceil(1000));
+    }
+
+    fn delay_us(&mut self, us: u32) {
+        self.inner.delay_us(us);
+    }
+}
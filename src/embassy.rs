@@ -0,0 +1,66 @@
+//! Async drivers timed by [`embassy-time`](embassy_time), so no delay needs to be passed
+//! around:
+//!
+//! ```ignore
+//! let mut bme280 = Timed::new(AsyncBME280::new_primary(i2c));
+//! bme280.init().await?;
+//! let measurements = bme280.measure().await?;
+//! ```
+
+use embassy_time::Delay;
+
+use crate::forward::forward_methods;
+
+/// Async driver using [`embassy_time::Delay`] for all waits
+#[derive(Debug, Default)]
+pub struct Timed<B> {
+    bme280: B,
+}
+
+impl<B> Timed<B> {
+    /// Wraps an async driver
+    pub fn new(bme280: B) -> Self {
+        Self { bme280 }
+    }
+
+    /// Returns the wrapped driver
+    pub fn inner(&mut self) -> &mut B {
+        &mut self.bme280
+    }
+
+    /// Releases the wrapped driver
+    pub fn into_inner(self) -> B {
+        self.bme280
+    }
+}
+
+/// Passes an [`embassy_time::Delay`] to the driver methods that wait
+macro_rules! timed {
+    ($this:ident, $delay:ident, $method:ident($($arg:expr),*)) => {
+        $this.bme280.$method(&mut Delay $(, $arg)*).await
+    };
+    ($this:ident, $method:ident($($arg:expr),*)) => {
+        $this.bme280.$method($($arg),*).await
+    };
+}
+
+macro_rules! impl_timed {
+    ($driver:ty, $bus:ident: $bus_trait:path, $error:ty) => {
+        forward_methods! {
+            impl[$bus: $bus_trait] Timed<$driver> where [];
+            receiver: [&mut], async: [async], delay: [], format: [], error: $error, call: timed;
+            setup, measure, control, read
+        }
+    };
+}
+
+impl_timed!(
+    crate::i2c::AsyncBME280<I2C>,
+    I2C: embedded_hal_async::i2c::I2c,
+    I2C::Error
+);
+impl_timed!(
+    crate::spi::AsyncBME280<SPI>,
+    SPI: embedded_hal_async::spi::SpiDevice,
+    SPI::Error
+);
@@ -0,0 +1,112 @@
+//! Type-erased driver facade, for frameworks holding sensors on different buses in a single
+//! collection:
+//!
+//! ```ignore
+//! let mut sensors: Vec<Box<dyn Bme280Driver>> = vec![
+//!     Box::new(bme280::i2c::BME280::new_primary(i2c)),
+//!     Box::new(bme280::spi::BME280::new(spi)?),
+//! ];
+//! for sensor in &mut sensors {
+//!     sensor.init(&mut delay)?;
+//! }
+//! ```
+
+use alloc::boxed::Box;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use embedded_hal::delay::DelayNs;
+
+use crate::{Error, Measurements};
+
+/// Bus error of a type-erased driver
+pub type DynBusError = Box<dyn Debug>;
+
+/// Object-safe subset of the blocking driver API
+pub trait Bme280Driver {
+    /// Initializes the sensor, see `BME280::init()`
+    fn init(&mut self, delay: &mut dyn DelayNs) -> Result<(), Error<DynBusError>>;
+
+    /// Captures and processes a measurement, see `BME280::measure()`
+    fn measure(
+        &mut self,
+        delay: &mut dyn DelayNs,
+    ) -> Result<Measurements<DynBusError>, Error<DynBusError>>;
+}
+
+/// Replaces the bus error with a boxed one
+fn erase_error<E: Debug + 'static>(error: Error<E>) -> Error<DynBusError> {
+    match error {
+        Error::CompensationFailed => Error::CompensationFailed,
+        Error::Bus(error) => Error::Bus(Box::new(error)),
+        Error::InvalidData => Error::InvalidData,
+        Error::NoCalibrationData => Error::NoCalibrationData,
+        Error::UnsupportedChip => Error::UnsupportedChip,
+        Error::Delay => Error::Delay,
+        Error::Timeout => Error::Timeout,
+        Error::WriteVerificationFailed => Error::WriteVerificationFailed,
+        Error::ImplausibleMeasurement => Error::ImplausibleMeasurement,
+    }
+}
+
+fn erase_measurements<E>(measurements: Measurements<E>) -> Measurements<DynBusError> {
+    Measurements {
+        temperature: measurements.temperature,
+        pressure: measurements.pressure,
+        humidity: measurements.humidity,
+        _e: PhantomData,
+    }
+}
+
+impl<I2C> Bme280Driver for crate::i2c::BME280<I2C>
+where
+    I2C: embedded_hal::i2c::I2c,
+    I2C::Error: 'static,
+{
+    fn init(&mut self, mut delay: &mut dyn DelayNs) -> Result<(), Error<DynBusError>> {
+        crate::i2c::BME280::init(self, &mut delay).map_err(erase_error)
+    }
+
+    fn measure(
+        &mut self,
+        mut delay: &mut dyn DelayNs,
+    ) -> Result<Measurements<DynBusError>, Error<DynBusError>> {
+        crate::i2c::BME280::measure(self, &mut delay)
+            .map(erase_measurements)
+            .map_err(erase_error)
+    }
+}
+
+impl<SPI> Bme280Driver for crate::spi::BME280<SPI>
+where
+    SPI: embedded_hal::spi::SpiDevice,
+    SPI::Error: 'static,
+{
+    fn init(&mut self, mut delay: &mut dyn DelayNs) -> Result<(), Error<DynBusError>> {
+        crate::spi::BME280::init(self, &mut delay).map_err(erase_error)
+    }
+
+    fn measure(
+        &mut self,
+        mut delay: &mut dyn DelayNs,
+    ) -> Result<Measurements<DynBusError>, Error<DynBusError>> {
+        crate::spi::BME280::measure(self, &mut delay)
+            .map(erase_measurements)
+            .map_err(erase_error)
+    }
+}
+
+impl crate::array::Sensor for Box<dyn Bme280Driver> {
+    type BusError = DynBusError;
+
+    fn init<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<DynBusError>> {
+        (**self).init(delay)
+    }
+
+    fn measure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<DynBusError>, Error<DynBusError>> {
+        (**self).measure(delay)
+    }
+}
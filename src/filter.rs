@@ -0,0 +1,231 @@
+//! Software filters for measurement streams.
+//!
+//! Unlike the IIR filter of the sensor, which only applies in normal mode, these filters work
+//! on any sequence of measurements, including forced mode ones:
+//!
+//! ```ignore
+//! let mut filter = EmaFilter::new(0.2);
+//! let measurements = bme280.measure(&mut delay).map(|m| filter.apply(m))?;
+//! ```
+
+use crate::Measurements;
+
+/// Exponential moving average of a single channel
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Ema {
+    alpha: f32,
+    value: Option<f32>,
+}
+
+impl Ema {
+    /// Creates a filter with the given smoothing factor.
+    /// `alpha` is saturated to the 0 to 1 range; smaller values smooth more, 1 disables the filter.
+    /// A NaN `alpha` disables the filter.
+    pub fn new(alpha: f32) -> Self {
+        Self {
+            alpha: if alpha.is_nan() {
+                1.0
+            } else {
+                alpha.clamp(0.0, 1.0)
+            },
+            value: None,
+        }
+    }
+
+    /// Returns the smoothing factor
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// Feeds a value and returns the filtered value.
+    /// The first value after creation or [`Self::reset`] is passed through unchanged.
+    pub fn update(&mut self, value: f32) -> f32 {
+        let filtered = match self.value {
+            Some(previous) => previous + self.alpha * (value - previous),
+            None => value,
+        };
+        self.value = Some(filtered);
+        filtered
+    }
+
+    /// Returns the current filtered value, or `None` if no value was fed yet
+    pub fn value(&self) -> Option<f32> {
+        self.value
+    }
+
+    /// Forgets the filtered value, so the next value is passed through unchanged
+    pub fn reset(&mut self) {
+        self.value = None;
+    }
+}
+
+/// Per-channel exponential moving average of measurements.
+/// Skipped pressure and humidity values stay skipped and leave their filter untouched.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EmaFilter {
+    /// filter for the temperature
+    pub temperature: Ema,
+    /// filter for the pressure
+    pub pressure: Ema,
+    /// filter for the humidity
+    pub humidity: Ema,
+}
+
+impl EmaFilter {
+    /// Creates a filter with the same smoothing factor for all channels
+    pub fn new(alpha: f32) -> Self {
+        Self::with_alphas(alpha, alpha, alpha)
+    }
+
+    /// Creates a filter with a smoothing factor per channel
+    pub fn with_alphas(temperature: f32, pressure: f32, humidity: f32) -> Self {
+        Self {
+            temperature: Ema::new(temperature),
+            pressure: Ema::new(pressure),
+            humidity: Ema::new(humidity),
+        }
+    }
+
+    /// Feeds a measurement and returns the filtered measurement
+    pub fn apply<E>(&mut self, mut measurements: Measurements<E>) -> Measurements<E> {
+        measurements.temperature = self.temperature.update(measurements.temperature);
+        measurements.pressure = measurements
+            .pressure
+            .map(|pressure| self.pressure.update(pressure));
+        measurements.humidity = measurements
+            .humidity
+            .map(|humidity| self.humidity.update(humidity));
+        measurements
+    }
+
+    /// Forgets the filtered values of all channels
+    pub fn reset(&mut self) {
+        self.temperature.reset();
+        self.pressure.reset();
+        self.humidity.reset();
+    }
+}
+
+/// One-dimensional Kalman filter tracking a value and its rate of change, e.g. the pressure
+/// and its vertical speed equivalent for a variometer.
+/// Unlike an exponential moving average, the rate estimate lets the filter follow steady
+/// changes without lag.
+#[cfg(feature = "kalman")]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Kalman {
+    process_noise: f32,
+    measurement_noise: f32,
+    /// estimated value and rate of change, `None` until the first value
+    state: Option<(f32, f32)>,
+    /// covariance of the estimate
+    covariance: [[f32; 2]; 2],
+}
+
+#[cfg(feature = "kalman")]
+impl Kalman {
+    /// Creates a filter with the given noise parameters.
+    /// `process_noise` is the spectral density of the changes of the rate, in squared units per
+    /// cubed second, and `measurement_noise` is the variance of the measured values in squared
+    /// units. A larger ratio of process to measurement noise follows changes faster but smooths
+    /// less.
+    pub fn new(process_noise: f32, measurement_noise: f32) -> Self {
+        Self {
+            process_noise,
+            measurement_noise,
+            state: None,
+            covariance: [[0.0; 2]; 2],
+        }
+    }
+
+    /// Feeds a value measured `dt` seconds after the previous one and returns the filtered
+    /// value.
+    /// The first value after creation or [`Self::reset`] is passed through unchanged.
+    pub fn update(&mut self, value: f32, dt: f32) -> f32 {
+        let Some((estimate, rate)) = self.state else {
+            self.state = Some((value, 0.0));
+            self.covariance = [[self.measurement_noise, 0.0], [0.0, self.measurement_noise]];
+            return value;
+        };
+
+        // predict with a constant rate model
+        let estimate = estimate + rate * dt;
+        let [[p00, p01], [p10, p11]] = self.covariance;
+        let q = self.process_noise;
+        let p00 = p00 + dt * (p10 + p01) + dt * dt * p11 + q * dt * dt * dt / 3.0;
+        let p01 = p01 + dt * p11 + q * dt * dt / 2.0;
+        let p10 = p10 + dt * p11 + q * dt * dt / 2.0;
+        let p11 = p11 + q * dt;
+
+        // correct with the measured value
+        let innovation = value - estimate;
+        let innovation_variance = p00 + self.measurement_noise;
+        let gain = (p00 / innovation_variance, p10 / innovation_variance);
+        let estimate = estimate + gain.0 * innovation;
+        let rate = rate + gain.1 * innovation;
+        self.covariance = [
+            [(1.0 - gain.0) * p00, (1.0 - gain.0) * p01],
+            [p10 - gain.1 * p00, p11 - gain.1 * p01],
+        ];
+        self.state = Some((estimate, rate));
+        estimate
+    }
+
+    /// Returns the current filtered value, or `None` if no value was fed yet
+    pub fn value(&self) -> Option<f32> {
+        self.state.map(|(value, _)| value)
+    }
+
+    /// Returns the estimated rate of change in units per second, or `None` if no value was fed
+    /// yet
+    pub fn rate(&self) -> Option<f32> {
+        self.state.map(|(_, rate)| rate)
+    }
+
+    /// Forgets the estimate, so the next value is passed through unchanged
+    pub fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+/// Kalman filter for the temperature and pressure of measurements.
+/// Humidity values are passed through unchanged, and skipped pressure values leave the
+/// pressure filter untouched.
+#[cfg(feature = "kalman")]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct KalmanFilter {
+    /// filter for the temperature
+    pub temperature: Kalman,
+    /// filter for the pressure
+    pub pressure: Kalman,
+}
+
+#[cfg(feature = "kalman")]
+impl KalmanFilter {
+    /// Creates a filter from the per-channel filters
+    pub fn new(temperature: Kalman, pressure: Kalman) -> Self {
+        Self {
+            temperature,
+            pressure,
+        }
+    }
+
+    /// Feeds a measurement taken `dt` seconds after the previous one and returns the filtered
+    /// measurement
+    pub fn apply<E>(&mut self, mut measurements: Measurements<E>, dt: f32) -> Measurements<E> {
+        measurements.temperature = self.temperature.update(measurements.temperature, dt);
+        measurements.pressure = measurements
+            .pressure
+            .map(|pressure| self.pressure.update(pressure, dt));
+        measurements
+    }
+
+    /// Forgets the estimates of all channels
+    pub fn reset(&mut self) {
+        self.temperature.reset();
+        self.pressure.reset();
+    }
+}
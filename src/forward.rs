@@ -0,0 +1,222 @@
+//! Driver methods forwarded by the wrappers, so every wrapper exposes the same methods with the
+//! same signatures.
+//!
+//! [`forward_methods!`] implements groups of methods for a wrapper:
+//!
+//! - `setup`: `init()`, `init_with_config()` and `soft_reset()`
+//! - `measure`: `measure()`, `measure_fixed_raw()` and `measure_as()`
+//! - `control`: `set_humidity_oversampling()`, `configuration()`, `status()`,
+//!   `start_normal_mode()`, `sleep()` and `wake()`
+//! - `read`: `trigger_measurement()`, `read_measurement()`, `read_measurement_fixed_raw()` and
+//!   `read_measurement_as()`
+//!
+//! The wrapper decides how the driver is reached through a call macro, which receives the
+//! receiver, the `delay` parameter for the methods of `setup` and `measure`, and the method with
+//! its arguments:
+//!
+//! ```ignore
+//! macro_rules! call {
+//!     ($this:ident, $delay:ident, $method:ident($($arg:expr),*)) => {
+//!         $this.bme280.$method(&mut $this.delay $(, $arg)*)
+//!     };
+//!     ($this:ident, $method:ident($($arg:expr),*)) => {
+//!         $this.bme280.$method($($arg),*)
+//!     };
+//! }
+//!
+//! forward_methods! {
+//!     impl[I2C: I2c, D: DelayNs] WithDelay<BME280<I2C>, D> where [];
+//!     receiver: [&mut], async: [], delay: [], format: [], error: I2C::Error, call: call;
+//!     setup, measure, control, read
+//! }
+//! ```
+//!
+//! `delay: [D: DelayNs]` adds a `delay: &mut D` parameter for wrappers taking the delay from the
+//! caller, and `format` adds bounds to the measurement format of `measure_as()` and
+//! `read_measurement_as()`.
+
+macro_rules! forward_methods {
+    (
+        impl $generics:tt $target:ty where $bounds:tt;
+        receiver: $receiver:tt,
+        async: $async:tt,
+        delay: $delay:tt,
+        format: $format:tt,
+        error: $error:ty,
+        call: $call:ident;
+        $($group:ident),+
+    ) => {
+        $(
+            $crate::forward::forward_methods!(
+                @$group $generics $target, $bounds, $receiver, $async, $delay, $format, $error,
+                $call
+            );
+        )+
+    };
+    (
+        @setup [$($generics:tt)*] $target:ty, [$($bounds:tt)*], [$($receiver:tt)*],
+        [$($async:tt)?], [$($delay:ident: $delay_trait:path)?], $format:tt, $error:ty,
+        $call:ident
+    ) => {
+        impl<$($generics)*> $target
+        where
+            $($bounds)*
+        {
+            /// Initializes the BME280, see `init()` of the driver
+            pub $($async)? fn init<$($delay: $delay_trait)?>(
+                $($receiver)* self,
+                $(delay: &mut $delay,)?
+            ) -> Result<(), $crate::Error<$error>> {
+                $call!(self, delay, init())
+            }
+
+            /// Initializes the BME280, applying the given configuration
+            pub $($async)? fn init_with_config<$($delay: $delay_trait)?>(
+                $($receiver)* self,
+                $(delay: &mut $delay,)?
+                config: $crate::Configuration,
+            ) -> Result<(), $crate::Error<$error>> {
+                $call!(self, delay, init_with_config(config))
+            }
+
+            /// Performs a soft reset of the sensor and applies the given configuration
+            pub $($async)? fn soft_reset<$($delay: $delay_trait)?>(
+                $($receiver)* self,
+                $(delay: &mut $delay,)?
+                config: $crate::Configuration,
+            ) -> Result<(), $crate::Error<$error>> {
+                $call!(self, delay, soft_reset(config))
+            }
+        }
+    };
+    (
+        @measure [$($generics:tt)*] $target:ty, [$($bounds:tt)*], [$($receiver:tt)*],
+        [$($async:tt)?], [$($delay:ident: $delay_trait:path)?], [$($format:tt)*], $error:ty,
+        $call:ident
+    ) => {
+        impl<$($generics)*> $target
+        where
+            $($bounds)*
+        {
+            /// Captures and processes sensor data for temperature, pressure, and humidity
+            #[cfg(feature = "float")]
+            pub $($async)? fn measure<$($delay: $delay_trait)?>(
+                $($receiver)* self,
+                $(delay: &mut $delay,)?
+            ) -> Result<$crate::Measurements<$error>, $crate::Error<$error>> {
+                $call!(self, delay, measure())
+            }
+
+            /// Captures and processes sensor data for temperature, pressure, and humidity in
+            /// integer format
+            pub $($async)? fn measure_fixed_raw<$($delay: $delay_trait)?>(
+                $($receiver)* self,
+                $(delay: &mut $delay,)?
+            ) -> Result<$crate::MeasurementsFixedRaw<$error>, $crate::Error<$error>> {
+                $call!(self, delay, measure_fixed_raw())
+            }
+
+            /// Captures and processes sensor data in the requested format
+            pub $($async)? fn measure_as<
+                M: $crate::MeasurementFormat<$error> $($format)*,
+                $($delay: $delay_trait)?
+            >(
+                $($receiver)* self,
+                $(delay: &mut $delay,)?
+            ) -> Result<M, $crate::Error<$error>> {
+                $call!(self, delay, measure_as())
+            }
+        }
+    };
+    (
+        @control [$($generics:tt)*] $target:ty, [$($bounds:tt)*], [$($receiver:tt)*],
+        [$($async:tt)?], $delay:tt, $format:tt, $error:ty, $call:ident
+    ) => {
+        impl<$($generics)*> $target
+        where
+            $($bounds)*
+        {
+            /// Changes the humidity oversampling setting at runtime
+            pub $($async)? fn set_humidity_oversampling(
+                $($receiver)* self,
+                oversampling: $crate::Oversampling,
+            ) -> Result<(), $crate::Error<$error>> {
+                $call!(self, set_humidity_oversampling(oversampling))
+            }
+
+            /// Reads back the configuration currently held by the sensor
+            pub $($async)? fn configuration(
+                $($receiver)* self,
+            ) -> Result<$crate::Configuration, $crate::Error<$error>> {
+                $call!(self, configuration())
+            }
+
+            /// Reads the status register of the sensor
+            pub $($async)? fn status(
+                $($receiver)* self,
+            ) -> Result<$crate::Status, $crate::Error<$error>> {
+                $call!(self, status())
+            }
+
+            /// Puts the sensor into normal mode
+            pub $($async)? fn start_normal_mode(
+                $($receiver)* self,
+            ) -> Result<(), $crate::Error<$error>> {
+                $call!(self, start_normal_mode())
+            }
+
+            /// Puts the sensor to sleep, see `sleep()` of the driver
+            pub $($async)? fn sleep($($receiver)* self) -> Result<(), $crate::Error<$error>> {
+                $call!(self, sleep())
+            }
+
+            /// Wakes the sensor, see `wake()` of the driver
+            pub $($async)? fn wake($($receiver)* self) -> Result<(), $crate::Error<$error>> {
+                $call!(self, wake())
+            }
+        }
+    };
+    (
+        @read [$($generics:tt)*] $target:ty, [$($bounds:tt)*], [$($receiver:tt)*],
+        [$($async:tt)?], $delay:tt, [$($format:tt)*], $error:ty, $call:ident
+    ) => {
+        impl<$($generics)*> $target
+        where
+            $($bounds)*
+        {
+            /// Triggers a single conversion in forced mode without waiting for it to finish
+            pub $($async)? fn trigger_measurement(
+                $($receiver)* self,
+            ) -> Result<(), $crate::Error<$error>> {
+                $call!(self, trigger_measurement())
+            }
+
+            /// Reads and processes the most recent sensor data without triggering a new
+            /// conversion
+            #[cfg(feature = "float")]
+            pub $($async)? fn read_measurement(
+                $($receiver)* self,
+            ) -> Result<$crate::Measurements<$error>, $crate::Error<$error>> {
+                $call!(self, read_measurement())
+            }
+
+            /// Reads and processes the most recent sensor data in raw fixed point format without
+            /// triggering a new conversion
+            pub $($async)? fn read_measurement_fixed_raw(
+                $($receiver)* self,
+            ) -> Result<$crate::MeasurementsFixedRaw<$error>, $crate::Error<$error>> {
+                $call!(self, read_measurement_fixed_raw())
+            }
+
+            /// Reads and processes the most recent sensor data in the requested format without
+            /// triggering a new conversion
+            pub $($async)? fn read_measurement_as<M: $crate::MeasurementFormat<$error> $($format)*>(
+                $($receiver)* self,
+            ) -> Result<M, $crate::Error<$error>> {
+                $call!(self, read_measurement_as())
+            }
+        }
+    };
+}
+
+pub(crate) use forward_methods;
@@ -0,0 +1,84 @@
+//! History of the most recent measurements, e.g. for trends, spike detection or sparklines.
+//!
+//! The history is filled by passing measurement results through it:
+//!
+//! ```ignore
+//! let mut history: History<Measurements<_>, 60> = History::new();
+//! let measurements = history.record(bme280.measure(&mut delay))?;
+//! for measurements in history.latest(10) {
+//!     // oldest first
+//! }
+//! ```
+
+use heapless::HistoryBuffer;
+
+/// Fixed-capacity history over the last `N` measurements of type `M`.
+/// Once full, each new measurement replaces the oldest one.
+#[derive(Debug, Clone)]
+pub struct History<M, const N: usize> {
+    buffer: HistoryBuffer<M, N>,
+}
+
+impl<M, const N: usize> Default for History<M, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M, const N: usize> History<M, N> {
+    /// Creates an empty history
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "the history must hold at least one measurement") };
+        Self {
+            buffer: HistoryBuffer::new(),
+        }
+    }
+
+    /// Adds a measurement, replacing the oldest one if the history is full
+    pub fn push(&mut self, measurements: M) {
+        self.buffer.write(measurements);
+    }
+
+    /// Adds the measurement of a successful result and returns a reference to it.
+    /// Errors are passed through and leave the history untouched.
+    pub fn record<E>(&mut self, result: Result<M, E>) -> Result<&M, E> {
+        self.push(result?);
+        Ok(self.buffer.recent().expect("a measurement was just added"))
+    }
+
+    /// Removes all measurements
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Returns the number of measurements in the history
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns whether the history holds no measurements
+    pub fn is_empty(&self) -> bool {
+        self.buffer.len() == 0
+    }
+
+    /// Returns whether the history holds `N` measurements
+    pub fn is_full(&self) -> bool {
+        self.buffer.len() == N
+    }
+
+    /// Returns the most recent measurement, or `None` if the history is empty
+    pub fn last(&self) -> Option<&M> {
+        self.buffer.recent()
+    }
+
+    /// Returns all measurements, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &M> {
+        self.buffer.oldest_ordered()
+    }
+
+    /// Returns the latest `count` measurements, oldest first, or all of them if the history
+    /// holds fewer
+    pub fn latest(&self, count: usize) -> impl Iterator<Item = &M> {
+        self.iter().skip(self.len().saturating_sub(count))
+    }
+}
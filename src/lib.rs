@@ -18,11 +18,9 @@
 #![cfg_attr(not(feature = "async"), deny(unstable_features))]
 // Turn off no_std if we turn on the "with_std" feature
 #![cfg_attr(not(feature = "with_std"), no_std)]
-#![cfg_attr(
-    feature = "async",
-    feature(type_alias_impl_trait),
-    feature(impl_trait_in_assoc_type)
-)]
+// Without the `float` feature, no floating point code may be compiled in.
+#![cfg_attr(not(feature = "float"), deny(clippy::float_arithmetic))]
+#![cfg_attr(feature = "async", feature(impl_trait_in_assoc_type))]
 
 //! A platform agnostic Rust driver for the Bosch BME280 and BMP280, based on the
 //! [`embedded-hal`](https://github.com/rust-embedded/embedded-hal) traits.
@@ -47,110 +45,205 @@
 //! // using Linux I2C Bus #1 in this example
 //! let i2c_bus = I2cdev::new("/dev/i2c-1").unwrap();
 //!
+//! let mut delay = Delay;
+//!
 //! // initialize the BME280 using the primary I2C address 0x76
-//! let mut bme280 = BME280::new_primary(i2c_bus, Delay);
+//! let mut bme280 = BME280::new_primary(i2c_bus);
 //!
 //! // or, initialize the BME280 using the secondary I2C address 0x77
-//! // let mut bme280 = BME280::new_secondary(i2c_bus, Delay);
+//! // let mut bme280 = BME280::new_secondary(i2c_bus);
 //!
 //! // or, initialize the BME280 using a custom I2C address
 //! // let bme280_i2c_addr = 0x88;
-//! // let mut bme280 = BME280::new(i2c_bus, bme280_i2c_addr, Delay);
+//! // let mut bme280 = BME280::new(i2c_bus, bme280_i2c_addr);
 //!
 //! // initialize the sensor
-//! bme280.init().unwrap();
+//! bme280.init(&mut delay).unwrap();
 //!
 //! // measure temperature, pressure, and humidity
-//! let measurements = bme280.measure().unwrap();
+//! let measurements = bme280.measure(&mut delay).unwrap();
 //!
-//! println!("Relative Humidity = {}%", measurements.humidity);
 //! println!("Temperature = {} deg C", measurements.temperature);
-//! println!("Pressure = {} pascals", measurements.pressure);
+//! if let Some(pressure) = measurements.pressure {
+//!     println!("Pressure = {} pascals", pressure);
+//! }
+//! if let Some(humidity) = measurements.humidity {
+//!     println!("Relative Humidity = {}%", humidity);
+//! }
 //! ```
 
+#[cfg(feature = "float")]
+pub mod altimeter;
+#[cfg(feature = "float")]
+pub mod array;
+#[cfg(all(feature = "async", feature = "with_std"))]
+pub mod blocking;
+pub mod clock;
+#[cfg(feature = "critical-section")]
+pub mod critical;
+pub mod csv;
+pub mod decimal;
+pub mod derived;
+#[cfg(feature = "eh02")]
+pub mod eh02;
+#[cfg(feature = "embassy-time")]
+pub mod embassy;
+#[cfg(all(feature = "alloc", feature = "sync", feature = "float"))]
+pub mod erased;
+#[cfg(feature = "float")]
+pub mod filter;
+mod forward;
+#[cfg(feature = "heapless")]
+pub mod history;
 pub mod i2c;
+mod math;
+pub mod mux;
+#[cfg(feature = "tokio")]
+pub mod offload;
+#[cfg(feature = "uom")]
+pub mod quantities;
+#[cfg(feature = "embedded-sensors")]
+pub mod sensors;
+#[cfg(feature = "embassy")]
+pub mod shared;
+#[cfg(feature = "simulator")]
+pub mod simulator;
 pub mod spi;
+#[cfg(feature = "sync")]
+pub mod split;
+#[cfg(feature = "float")]
+pub mod stats;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod trend;
+pub mod with_delay;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 use core::fmt::Debug;
 #[cfg(feature = "async")]
 use core::future::Future;
 use core::marker::PhantomData;
+use core::num::NonZeroU16;
+use decimal::Decimal;
 #[cfg(feature = "sync")]
 use embedded_hal::delay::DelayNs;
 #[cfg(feature = "async")]
 use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
 
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "with_defmt")]
+#[cfg(feature = "defmt")]
 use defmt::{Format, Formatter, write};
 
-#[cfg(feature = "with_std")]
-use derive_more::Display;
-#[cfg(feature = "with_std")]
-use std::error;
-#[cfg(feature = "with_std")]
-use std::fmt;
+const BME280_PWR_CTRL_ADDR: u8 = registers::CTRL_MEAS;
+const BME280_CTRL_HUM_ADDR: u8 = registers::CTRL_HUM;
+const BME280_CTRL_MEAS_ADDR: u8 = registers::CTRL_MEAS;
+const BME280_CONFIG_ADDR: u8 = registers::CONFIG;
 
-const BME280_PWR_CTRL_ADDR: u8 = 0xF4;
-const BME280_CTRL_HUM_ADDR: u8 = 0xF2;
-const BME280_CTRL_MEAS_ADDR: u8 = 0xF4;
-const BME280_CONFIG_ADDR: u8 = 0xF5;
-
-const BME280_RESET_ADDR: u8 = 0xE0;
-const BME280_SOFT_RESET_CMD: u8 = 0xB6;
+const BME280_RESET_ADDR: u8 = registers::RESET;
+const BME280_SOFT_RESET_CMD: u8 = registers::SOFT_RESET_CMD;
 
 const BME280_CHIP_ID: u8 = 0x60;
 const BMP280_CHIP_ID: u8 = 0x58;
-const BME280_CHIP_ID_ADDR: u8 = 0xD0;
+const BMP280_SAMPLE_CHIP_ID_1: u8 = 0x56;
+const BMP280_SAMPLE_CHIP_ID_2: u8 = 0x57;
+const BME280_CHIP_ID_ADDR: u8 = registers::CHIP_ID;
+
+const BME280_STATUS_ADDR: u8 = registers::STATUS;
+
+const BME280_POLL_INITIAL_US: u32 = 250;
+const BME280_POLL_MAX_US: u32 = 4000;
+const BME280_POLL_TIMEOUT_US: u32 = 200_000;
+
+const BME280_DATA_ADDR: u8 = registers::DATA;
+const BME280_P_T_H_DATA_LEN: usize = registers::DATA_LEN;
+const BME280_P_T_DATA_LEN: usize = 6;
+/// length of a burst from the status register through the last data register
+const BME280_STATUS_DATA_LEN: usize =
+    (registers::DATA - registers::STATUS) as usize + BME280_P_T_H_DATA_LEN;
 
-const BME280_DATA_ADDR: u8 = 0xF7;
-const BME280_P_T_H_DATA_LEN: usize = 8;
+const BME280_TEMPERATURE_DATA_ADDR: u8 = registers::TEMPERATURE_DATA;
+const BME280_T_DATA_LEN: usize = 3;
 
-const BME280_P_T_CALIB_DATA_ADDR: u8 = 0x88;
+const BME280_SKIPPED_TEMPERATURE: u32 = 0x80000;
+const BME280_SKIPPED_PRESSURE: u32 = 0x80000;
+const BME280_SKIPPED_HUMIDITY: u32 = 0x8000;
+
+const BME280_P_T_CALIB_DATA_ADDR: u8 = registers::CALIB_PT;
 const BME280_P_T_CALIB_DATA_LEN: usize = 26;
 
-const BME280_H_CALIB_DATA_ADDR: u8 = 0xE1;
+const BME280_H_CALIB_DATA_ADDR: u8 = registers::CALIB_H;
 const BME280_H_CALIB_DATA_LEN: usize = 7;
+/// Length of a serialized [`CalibrationData`] snapshot, see [`CalibrationData::to_bytes`]
+pub const CALIBRATION_SNAPSHOT_LEN: usize = 51;
+/// Format version of the serialized calibration snapshot
+const CALIBRATION_SNAPSHOT_VERSION: u8 = 1;
 
+#[cfg(feature = "float")]
 const BME280_TEMP_MIN: f32 = -40.0;
+#[cfg(feature = "float")]
 const BME280_TEMP_MAX: f32 = 85.0;
 const BME280_TEMP_MIN_FIXED: i32 = -4000;
 const BME280_TEMP_MAX_FIXED: i32 = 8500;
 
+#[cfg(feature = "float")]
 const BME280_PRESSURE_MIN: f32 = 30000.0;
+#[cfg(feature = "float")]
 const BME280_PRESSURE_MAX: f32 = 110000.0;
 const BME280_PRESSURE_MIN_FIXED: u32 = 30000 * 256;
 const BME280_PRESSURE_MAX_FIXED: u32 = 110000 * 256;
 
+#[cfg(feature = "float")]
 const BME280_HUMIDITY_MIN: f32 = 0.0;
+#[cfg(feature = "float")]
 const BME280_HUMIDITY_MAX: f32 = 100.0;
 const BME280_HUMIDITY_MIN_FIXED: u32 = 0;
 const BME280_HUMIDITY_MAX_FIXED: u32 = 100 * 1024;
 
-const BME280_SLEEP_MODE: u8 = 0x00;
-const BME280_FORCED_MODE: u8 = 0x01;
-const BME280_NORMAL_MODE: u8 = 0x03;
-
-const BME280_SENSOR_MODE_MSK: u8 = 0x03;
+/// Humidity gain of 1.0 in Q16.16 format
+const BME280_HUMIDITY_GAIN_UNITY: u32 = 1 << 16;
 
-const BME280_CTRL_HUM_MSK: u8 = 0x07;
+const BME280_SLEEP_MODE: u8 = registers::MODE_SLEEP;
+const BME280_FORCED_MODE: u8 = registers::MODE_FORCED;
+const BME280_NORMAL_MODE: u8 = registers::MODE_NORMAL;
 
-const BME280_CTRL_PRESS_MSK: u8 = 0x1C;
-const BME280_CTRL_PRESS_POS: u8 = 0x02;
+const BME280_SENSOR_MODE_MSK: u8 = registers::CtrlMeas::MODE_MASK;
 
-const BME280_CTRL_TEMP_MSK: u8 = 0xE0;
-const BME280_CTRL_TEMP_POS: u8 = 0x05;
+const BME280_CTRL_HUM_MSK: u8 = registers::CtrlHum::OSRS_H_MASK;
 
-const BME280_FILTER_MSK: u8 = 0x1C;
-const BME280_FILTER_POS: u8 = 0x02;
 const BME280_FILTER_COEFF_OFF: u8 = 0x00;
 const BME280_FILTER_COEFF_2: u8 = 0x01;
 const BME280_FILTER_COEFF_4: u8 = 0x02;
 const BME280_FILTER_COEFF_8: u8 = 0x03;
 const BME280_FILTER_COEFF_16: u8 = 0x04;
 
+const BME280_SPI3W_EN_MSK: u8 = registers::Config::SPI3W_EN_MASK;
+const BME280_CONFIG_MSK: u8 = registers::Config::WRITABLE_MASK;
+
+const BME280_STANDBY_TIME_0_5_MS: u8 = 0x00;
+const BME280_STANDBY_TIME_62_5_MS: u8 = 0x01;
+const BME280_STANDBY_TIME_125_MS: u8 = 0x02;
+const BME280_STANDBY_TIME_250_MS: u8 = 0x03;
+const BME280_STANDBY_TIME_500_MS: u8 = 0x04;
+const BME280_STANDBY_TIME_1000_MS: u8 = 0x05;
+const BME280_STANDBY_TIME_10_MS: u8 = 0x06;
+const BME280_STANDBY_TIME_20_MS: u8 = 0x07;
+
+#[cfg(feature = "float")]
+const BME280_CURRENT_TEMPERATURE_UA: f32 = 350.0;
+#[cfg(feature = "float")]
+const BME280_CURRENT_PRESSURE_UA: f32 = 714.0;
+#[cfg(feature = "float")]
+const BME280_CURRENT_HUMIDITY_UA: f32 = 340.0;
+#[cfg(feature = "float")]
+const BME280_CURRENT_SLEEP_UA: f32 = 0.1;
+#[cfg(feature = "float")]
+const BME280_CURRENT_STANDBY_UA: f32 = 0.2;
+
+const BME280_NO_OVERSAMPLING: u8 = 0x00;
 const BME280_OVERSAMPLING_1X: u8 = 0x01;
 const BME280_OVERSAMPLING_2X: u8 = 0x02;
 const BME280_OVERSAMPLING_4X: u8 = 0x03;
@@ -169,8 +262,26 @@ macro_rules! set_bits {
     };
 }
 
+macro_rules! get_bits {
+    ($reg_data:expr, $mask:expr, $pos:expr) => {
+        ($reg_data & $mask) >> $pos
+    };
+}
+
+/// Emits a debug trace through `log` and/or `defmt`, depending on the enabled features
+macro_rules! debug_log {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log")]
+        log::debug!($($arg)*);
+        #[cfg(feature = "defmt-log")]
+        defmt::debug!($($arg)*);
+    };
+}
+
+pub mod registers;
+pub mod retry;
+
 /// BME280 errors
-#[cfg_attr(feature = "with_std", derive(Display))]
 #[derive(Debug)]
 pub enum Error<E> {
     /// Failed to compensate a raw measurement
@@ -185,9 +296,15 @@ pub enum Error<E> {
     UnsupportedChip,
     /// Delay error
     Delay,
+    /// The sensor did not finish a conversion in time
+    Timeout,
+    /// A register did not hold the written value when read back
+    WriteVerificationFailed,
+    /// A measurement was out of the sensor's operating range or held power-on reset values
+    ImplausibleMeasurement,
 }
 
-#[cfg(feature = "with_defmt")]
+#[cfg(feature = "defmt")]
 impl<E> Format for Error<E> {
     fn format(&self, fmt: Formatter) {
         match self {
@@ -197,16 +314,78 @@ impl<E> Format for Error<E> {
             Error::NoCalibrationData => write!(fmt, "No calibration data"),
             Error::UnsupportedChip => write!(fmt, "Unsupported chip"),
             Error::Delay => write!(fmt, "Delay issue"),
+            Error::Timeout => write!(fmt, "Timeout"),
+            Error::WriteVerificationFailed => write!(fmt, "Write verification failure"),
+            Error::ImplausibleMeasurement => write!(fmt, "Implausible measurement"),
+        }
+    }
+}
+
+impl<E> Error<E> {
+    fn message(&self) -> &'static str {
+        match self {
+            Error::CompensationFailed => "Compensation failure",
+            Error::Bus(_) => "Bus error",
+            Error::InvalidData => "Invalid data",
+            Error::NoCalibrationData => "No calibration data",
+            Error::UnsupportedChip => "Unsupported chip",
+            Error::Delay => "Delay issue",
+            Error::Timeout => "Timeout",
+            Error::WriteVerificationFailed => "Write verification failure",
+            Error::ImplausibleMeasurement => "Implausible measurement",
         }
     }
 }
 
-#[cfg(feature = "with_std")]
-impl<T: fmt::Debug + fmt::Display> error::Error for Error<T> {}
+impl<E> core::fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<E> ufmt::uDisplay for Error<E> {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<E> ufmt::uDebug for Error<E> {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        f.write_str(match self {
+            Error::CompensationFailed => "CompensationFailed",
+            Error::Bus(_) => "Bus(_)",
+            Error::InvalidData => "InvalidData",
+            Error::NoCalibrationData => "NoCalibrationData",
+            Error::UnsupportedChip => "UnsupportedChip",
+            Error::Delay => "Delay",
+            Error::Timeout => "Timeout",
+            Error::WriteVerificationFailed => "WriteVerificationFailed",
+            Error::ImplausibleMeasurement => "ImplausibleMeasurement",
+        })
+    }
+}
+
+/// The bus error, if any, is reported as the source.
+impl<E: core::error::Error + 'static> core::error::Error for Error<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::Bus(error) => Some(error),
+            _ => None,
+        }
+    }
+}
 
 /// BME280 operating mode
-#[derive(Debug, Copy, Clone)]
-#[cfg_attr(feature = "with_defmt", derive(defmt::Format))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum SensorMode {
     /// Sleep mode
     Sleep,
@@ -216,15 +395,120 @@ pub enum SensorMode {
     Normal,
 }
 
+/// Sensor variants supported by this driver, as identified by their chip ID
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ChipModel {
+    /// BME280 temperature, pressure, and humidity sensor
+    BME280,
+    /// BMP280 temperature and pressure sensor
+    BMP280,
+}
+
+impl ChipModel {
+    fn from_id(chip_id: u8) -> Option<Self> {
+        match chip_id {
+            BME280_CHIP_ID => Some(ChipModel::BME280),
+            BMP280_CHIP_ID | BMP280_SAMPLE_CHIP_ID_1 | BMP280_SAMPLE_CHIP_ID_2 => {
+                Some(ChipModel::BMP280)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether this sensor measures relative humidity
+    pub fn has_humidity(&self) -> bool {
+        matches!(self, ChipModel::BME280)
+    }
+}
+
+/// Health counters of a driver, e.g. for a gateway exporting sensor statistics.
+/// The counters wrap around on overflow.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Metrics {
+    /// register accesses of the driver, i.e. reads of consecutive registers and register writes,
+    /// including failed ones.
+    /// An access may take several bus transactions: reads split by a transfer limit or by
+    /// separate write and read transfers on I²C, and retries of a [`Retry`](retry::Retry) device,
+    /// see its `retries()`, are counted once.
+    pub register_accesses: u32,
+    /// register accesses that failed with a bus error
+    pub bus_errors: u32,
+    /// measurements rejected by validation, see `BME280::set_validation()`
+    pub validation_failures: u32,
+}
+
+/// Maintains the [`Metrics`] of a driver when the `metrics` feature is enabled
+#[derive(Debug, Default)]
+struct MetricsCounter {
+    #[cfg(feature = "metrics")]
+    metrics: Metrics,
+}
+
+impl MetricsCounter {
+    /// Counts a register access and passes its result through
+    fn record<T, E>(&mut self, result: Result<T, Error<E>>) -> Result<T, Error<E>> {
+        #[cfg(feature = "metrics")]
+        {
+            self.metrics.register_accesses = self.metrics.register_accesses.wrapping_add(1);
+            if let Err(Error::Bus(_)) = result {
+                self.metrics.bus_errors = self.metrics.bus_errors.wrapping_add(1);
+            }
+        }
+        result
+    }
+
+    fn record_validation_failure(&mut self) {
+        #[cfg(feature = "metrics")]
+        {
+            self.metrics.validation_failures = self.metrics.validation_failures.wrapping_add(1);
+        }
+    }
+}
+
+/// Actions taken by `BME280::recover()`.
+/// Automatic recovery only runs on forced conversions, so normal mode is only resumed by explicit
+/// `recover()` calls.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RecoveryReport {
+    /// sensor variant found after the reset
+    pub chip_model: ChipModel,
+    /// whether the calibration data was read from the sensor, rather than reused
+    pub calibration_read: bool,
+    /// whether normal mode was restarted, because the driver had started it and no reset or
+    /// sleep stopped it since
+    pub normal_mode_resumed: bool,
+}
+
+/// BME280 device status, as reported by the status register
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Status {
+    /// A conversion is running.
+    /// This is cleared once the results have been transferred to the data registers.
+    pub measuring: bool,
+    /// The calibration data is being copied from NVM to the image registers.
+    /// This happens at power on and after a reset.
+    pub im_update: bool,
+}
+
 /// Oversampling settings for temperature, pressure, and humidity measurements.
 /// See sections 3.4ff of the manual for measurement flow and recommended values.
 /// The default is 1x, i.e., no oversampling.
-#[derive(Debug, Copy, Clone)]
-#[cfg_attr(feature = "with_defmt", derive(defmt::Format))]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Oversampling {
+    /// Skips the measurement entirely.
+    /// Skipped pressure and humidity measurements are reported as `None`. Temperature should not
+    /// be skipped, as the compensation of the other measurements depends on it.
+    Skipped,
     /// Disables oversampling.
     /// Without IIR filtering, this sets the resolution of temperature and pressure measurements
     /// to 16 bits.
+    #[default]
     Oversampling1X,
     /// Configures 2x oversampling.
     /// This increases the resolution of temperature and pressure measurements to 17 bits without
@@ -247,6 +531,7 @@ pub enum Oversampling {
 impl Oversampling {
     fn bits(&self) -> u8 {
         match self {
+            Oversampling::Skipped => BME280_NO_OVERSAMPLING,
             Oversampling::Oversampling1X => BME280_OVERSAMPLING_1X,
             Oversampling::Oversampling2X => BME280_OVERSAMPLING_2X,
             Oversampling::Oversampling4X => BME280_OVERSAMPLING_4X,
@@ -254,19 +539,36 @@ impl Oversampling {
             Oversampling::Oversampling16X => BME280_OVERSAMPLING_16X,
         }
     }
-}
 
-impl Default for Oversampling {
-    fn default() -> Self {
-        Self::Oversampling1X
+    fn from_bits(bits: u8) -> Self {
+        match bits {
+            BME280_NO_OVERSAMPLING => Oversampling::Skipped,
+            BME280_OVERSAMPLING_1X => Oversampling::Oversampling1X,
+            BME280_OVERSAMPLING_2X => Oversampling::Oversampling2X,
+            BME280_OVERSAMPLING_4X => Oversampling::Oversampling4X,
+            BME280_OVERSAMPLING_8X => Oversampling::Oversampling8X,
+            // All remaining values select 16x oversampling.
+            _ => Oversampling::Oversampling16X,
+        }
+    }
+
+    fn factor(&self) -> u32 {
+        match self {
+            Oversampling::Skipped => 0,
+            Oversampling::Oversampling1X => 1,
+            Oversampling::Oversampling2X => 2,
+            Oversampling::Oversampling4X => 4,
+            Oversampling::Oversampling8X => 8,
+            Oversampling::Oversampling16X => 16,
+        }
     }
 }
 
 /// Lowpass filter settings for pressure and temperature values.
 /// See section 3.4.4 of the datasheet for more information on this.
 /// The default setting is disabled.
-#[derive(Debug, Copy, Clone, Default)]
-#[cfg_attr(feature = "with_defmt", derive(defmt::Format))]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum IIRFilter {
     /// Disables the IIR filter.
     /// The resolution of pressure and temperature measurements is dictated by their respective
@@ -299,20 +601,176 @@ impl IIRFilter {
             IIRFilter::Coefficient16 => BME280_FILTER_COEFF_16,
         }
     }
+
+    /// Number of samples needed to reach 75% of a step response, see table 6 of the datasheet
+    fn step_response_samples(&self) -> u32 {
+        match self {
+            IIRFilter::Off => 1,
+            IIRFilter::Coefficient2 => 2,
+            IIRFilter::Coefficient4 => 5,
+            IIRFilter::Coefficient8 => 11,
+            IIRFilter::Coefficient16 => 22,
+        }
+    }
+
+    fn from_bits(bits: u8) -> Self {
+        match bits {
+            BME280_FILTER_COEFF_OFF => IIRFilter::Off,
+            BME280_FILTER_COEFF_2 => IIRFilter::Coefficient2,
+            BME280_FILTER_COEFF_4 => IIRFilter::Coefficient4,
+            BME280_FILTER_COEFF_8 => IIRFilter::Coefficient8,
+            // All remaining values select a coefficient of 16.
+            _ => IIRFilter::Coefficient16,
+        }
+    }
+}
+
+/// Inactive duration between two conversions in normal mode.
+/// See section 3.3.4 of the datasheet for more information on this.
+/// The default setting is 0.5 ms.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum StandbyTime {
+    /// Sets the standby time to 0.5 ms.
+    #[default]
+    Millis0_5,
+
+    /// Sets the standby time to 62.5 ms.
+    Millis62_5,
+
+    /// Sets the standby time to 125 ms.
+    Millis125,
+
+    /// Sets the standby time to 250 ms.
+    Millis250,
+
+    /// Sets the standby time to 500 ms.
+    Millis500,
+
+    /// Sets the standby time to 1000 ms.
+    Millis1000,
+
+    /// Sets the standby time to 10 ms.
+    /// The BMP280 uses a standby time of 2000 ms for this setting instead.
+    Millis10,
+
+    /// Sets the standby time to 20 ms.
+    /// The BMP280 uses a standby time of 4000 ms for this setting instead.
+    Millis20,
+}
+
+impl StandbyTime {
+    fn bits(&self) -> u8 {
+        match self {
+            StandbyTime::Millis0_5 => BME280_STANDBY_TIME_0_5_MS,
+            StandbyTime::Millis62_5 => BME280_STANDBY_TIME_62_5_MS,
+            StandbyTime::Millis125 => BME280_STANDBY_TIME_125_MS,
+            StandbyTime::Millis250 => BME280_STANDBY_TIME_250_MS,
+            StandbyTime::Millis500 => BME280_STANDBY_TIME_500_MS,
+            StandbyTime::Millis1000 => BME280_STANDBY_TIME_1000_MS,
+            StandbyTime::Millis10 => BME280_STANDBY_TIME_10_MS,
+            StandbyTime::Millis20 => BME280_STANDBY_TIME_20_MS,
+        }
+    }
+
+    /// Returns the standby time of the BME280 in microseconds.
+    pub fn as_micros(&self) -> u32 {
+        match self {
+            StandbyTime::Millis0_5 => 500,
+            StandbyTime::Millis62_5 => 62_500,
+            StandbyTime::Millis125 => 125_000,
+            StandbyTime::Millis250 => 250_000,
+            StandbyTime::Millis500 => 500_000,
+            StandbyTime::Millis1000 => 1_000_000,
+            StandbyTime::Millis10 => 10_000,
+            StandbyTime::Millis20 => 20_000,
+        }
+    }
+
+    fn from_bits(bits: u8) -> Self {
+        match bits {
+            BME280_STANDBY_TIME_0_5_MS => StandbyTime::Millis0_5,
+            BME280_STANDBY_TIME_62_5_MS => StandbyTime::Millis62_5,
+            BME280_STANDBY_TIME_125_MS => StandbyTime::Millis125,
+            BME280_STANDBY_TIME_250_MS => StandbyTime::Millis250,
+            BME280_STANDBY_TIME_500_MS => StandbyTime::Millis500,
+            BME280_STANDBY_TIME_1000_MS => StandbyTime::Millis1000,
+            BME280_STANDBY_TIME_10_MS => StandbyTime::Millis10,
+            _ => StandbyTime::Millis20,
+        }
+    }
+}
+
+/// Sampling strategy used to estimate the power consumption of a [`Configuration`]
+#[cfg(feature = "float")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SamplingStrategy {
+    /// Forced measurements at the given rate in hertz, with the sensor sleeping in between
+    Forced {
+        /// measurements per second
+        rate_hz: f32,
+    },
+    /// Continuous conversions in normal mode, using the configured standby time
+    Normal,
 }
 
 /// Configuration values for the BME280 sensor.
-/// The default sets all oversampling settings to 1x and disables the IIR filter.
-#[derive(Debug, Copy, Clone, Default)]
-#[cfg_attr(feature = "with_defmt", derive(defmt::Format))]
+/// The default sets all oversampling settings to 1x, disables the IIR filter and sets the
+/// normal mode standby time to 0.5 ms.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Configuration {
     temperature_oversampling: Oversampling,
     pressure_oversampling: Oversampling,
     humidity_oversampling: Oversampling,
     iir_filter: IIRFilter,
+    standby_time: StandbyTime,
 }
 
 impl Configuration {
+    /// Recommended settings for weather monitoring, see section 3.5.1 of the datasheet.
+    /// Use with forced measurements at one sample per minute.
+    pub fn weather_monitoring() -> Self {
+        Self::default()
+            .with_temperature_oversampling(Oversampling::Oversampling1X)
+            .with_pressure_oversampling(Oversampling::Oversampling1X)
+            .with_humidity_oversampling(Oversampling::Oversampling1X)
+            .with_iir_filter(IIRFilter::Off)
+    }
+
+    /// Recommended settings for humidity sensing, see section 3.5.2 of the datasheet.
+    /// Use with forced measurements at one sample per second.
+    pub fn humidity_sensing() -> Self {
+        Self::default()
+            .with_temperature_oversampling(Oversampling::Oversampling1X)
+            .with_pressure_oversampling(Oversampling::Skipped)
+            .with_humidity_oversampling(Oversampling::Oversampling1X)
+            .with_iir_filter(IIRFilter::Off)
+    }
+
+    /// Recommended settings for indoor navigation, see section 3.5.3 of the datasheet.
+    /// Use in normal mode.
+    pub fn indoor_navigation() -> Self {
+        Self::default()
+            .with_temperature_oversampling(Oversampling::Oversampling2X)
+            .with_pressure_oversampling(Oversampling::Oversampling16X)
+            .with_humidity_oversampling(Oversampling::Oversampling1X)
+            .with_iir_filter(IIRFilter::Coefficient16)
+            .with_standby_time(StandbyTime::Millis0_5)
+    }
+
+    /// Recommended settings for gaming, see section 3.5.4 of the datasheet.
+    /// Use in normal mode.
+    pub fn gaming() -> Self {
+        Self::default()
+            .with_temperature_oversampling(Oversampling::Oversampling1X)
+            .with_pressure_oversampling(Oversampling::Oversampling4X)
+            .with_humidity_oversampling(Oversampling::Skipped)
+            .with_iir_filter(IIRFilter::Coefficient16)
+            .with_standby_time(StandbyTime::Millis0_5)
+    }
+
     /// Sets the temperature oversampling setting.
     pub fn with_temperature_oversampling(mut self, oversampling: Oversampling) -> Self {
         self.temperature_oversampling = oversampling;
@@ -336,46 +794,219 @@ impl Configuration {
         self.iir_filter = filter;
         self
     }
+
+    /// Sets the standby time between conversions in normal mode.
+    pub fn with_standby_time(mut self, standby_time: StandbyTime) -> Self {
+        self.standby_time = standby_time;
+        self
+    }
+
+    /// Returns the temperature oversampling setting.
+    pub fn temperature_oversampling(&self) -> Oversampling {
+        self.temperature_oversampling
+    }
+
+    /// Returns the pressure oversampling setting.
+    pub fn pressure_oversampling(&self) -> Oversampling {
+        self.pressure_oversampling
+    }
+
+    /// Returns the humidity oversampling setting.
+    pub fn humidity_oversampling(&self) -> Oversampling {
+        self.humidity_oversampling
+    }
+
+    /// Returns the IIR filter setting.
+    pub fn iir_filter(&self) -> IIRFilter {
+        self.iir_filter
+    }
+
+    /// Returns the standby time between conversions in normal mode.
+    pub fn standby_time(&self) -> StandbyTime {
+        self.standby_time
+    }
+
+    /// Returns the maximum duration of a single conversion with these oversampling settings in
+    /// microseconds.
+    /// See section 9.1 of the datasheet for the formula.
+    pub fn max_measurement_time_us(&self) -> u32 {
+        let temperature = 2300 * self.temperature_oversampling.factor();
+        let pressure = match self.pressure_oversampling {
+            Oversampling::Skipped => 0,
+            oversampling => 2300 * oversampling.factor() + 575,
+        };
+        let humidity = match self.humidity_oversampling {
+            Oversampling::Skipped => 0,
+            oversampling => 2300 * oversampling.factor() + 575,
+        };
+        1250 + temperature + pressure + humidity
+    }
+
+    /// Returns the typical duration of a single conversion with these oversampling settings in
+    /// microseconds.
+    /// See section 9.1 of the datasheet for the formula.
+    pub fn typical_measurement_time_us(&self) -> u32 {
+        let (temperature, pressure, humidity) = self.typical_channel_times_us();
+        1000 + temperature + pressure + humidity
+    }
+
+    /// Returns the typical duration of one measurement cycle in normal mode in microseconds,
+    /// i.e. the conversion time plus the standby time.
+    pub fn cycle_time_us(&self) -> u32 {
+        self.typical_measurement_time_us() + self.standby_time.as_micros()
+    }
+
+    /// Returns the typical output data rate in normal mode in hertz.
+    /// See section 3.6 of the datasheet.
+    #[cfg(feature = "float")]
+    pub fn max_odr(&self) -> f32 {
+        1_000_000.0 / self.cycle_time_us() as f32
+    }
+
+    /// Returns the typical time in microseconds for pressure and temperature readings in normal
+    /// mode to reach 75% of a step change, taking the IIR filter into account.
+    pub fn response_time_us(&self) -> u32 {
+        self.cycle_time_us() * self.iir_filter.step_response_samples()
+    }
+
+    /// Typical conversion times of the temperature, pressure, and humidity channels
+    fn typical_channel_times_us(&self) -> (u32, u32, u32) {
+        let temperature = 2000 * self.temperature_oversampling.factor();
+        let pressure = match self.pressure_oversampling {
+            Oversampling::Skipped => 0,
+            oversampling => 2000 * oversampling.factor() + 500,
+        };
+        let humidity = match self.humidity_oversampling {
+            Oversampling::Skipped => 0,
+            oversampling => 2000 * oversampling.factor() + 500,
+        };
+        (temperature, pressure, humidity)
+    }
+
+    /// Estimates the average current draw in µA when sampling with this configuration, based
+    /// on the typical currents and conversion times given in section 9 of the datasheet.
+    #[cfg(feature = "float")]
+    pub fn estimated_current_ua(&self, strategy: SamplingStrategy) -> f32 {
+        let (temperature, pressure, humidity) = self.typical_channel_times_us();
+        // The startup time at the beginning of each conversion is accounted as temperature
+        // measurement time.
+        let charge = BME280_CURRENT_TEMPERATURE_UA * (1000 + temperature) as f32
+            + BME280_CURRENT_PRESSURE_UA * pressure as f32
+            + BME280_CURRENT_HUMIDITY_UA * humidity as f32;
+        let measurement_time = self.typical_measurement_time_us() as f32;
+
+        match strategy {
+            SamplingStrategy::Forced { rate_hz } => {
+                let duty_cycle = (measurement_time * rate_hz / 1_000_000.0).min(1.0);
+                charge * rate_hz / 1_000_000.0 + BME280_CURRENT_SLEEP_UA * (1.0 - duty_cycle)
+            }
+            SamplingStrategy::Normal => {
+                let standby_time = self.standby_time.as_micros() as f32;
+                (charge + BME280_CURRENT_STANDBY_UA * standby_time)
+                    / (measurement_time + standby_time)
+            }
+        }
+    }
 }
 
-#[derive(Debug)]
-#[cfg_attr(feature = "with_defmt", derive(defmt::Format))]
-struct CalibrationData {
-    dig_t1: u16,
-    dig_t2: i16,
-    dig_t3: i16,
-    dig_p1: u16,
-    dig_p2: i16,
-    dig_p3: i16,
-    dig_p4: i16,
-    dig_p5: i16,
-    dig_p6: i16,
-    dig_p7: i16,
-    dig_p8: i16,
-    dig_p9: i16,
-    dig_h1: u8,
-    dig_h2: i16,
-    dig_h3: u8,
-    dig_h4: i16,
-    dig_h5: i16,
-    dig_h6: i8,
+/// Contents of the configuration registers, with the mode bits left in sleep mode
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct ShadowRegisters {
+    ctrl_hum: u8,
+    ctrl_meas: u8,
+    config: u8,
+}
+
+impl ShadowRegisters {
+    fn new(configuration: &Configuration, spi3w_en: bool) -> Self {
+        let ctrl_hum = registers::CtrlHum::default()
+            .with_humidity_oversampling(configuration.humidity_oversampling);
+        let ctrl_meas = registers::CtrlMeas::default()
+            .with_temperature_oversampling(configuration.temperature_oversampling)
+            .with_pressure_oversampling(configuration.pressure_oversampling);
+        let config = registers::Config::default()
+            .with_standby_time(configuration.standby_time)
+            .with_iir_filter(configuration.iir_filter)
+            .with_spi3w_enabled(spi3w_en);
+
+        Self {
+            ctrl_hum: ctrl_hum.bits(),
+            ctrl_meas: ctrl_meas.bits(),
+            config: config.bits(),
+        }
+    }
+}
+
+/// Factory calibration coefficients of a sensor, needed to compensate raw measurements
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CalibrationData {
+    /// temperature compensation coefficient dig_T1
+    pub dig_t1: u16,
+    /// temperature compensation coefficient dig_T2
+    pub dig_t2: i16,
+    /// temperature compensation coefficient dig_T3
+    pub dig_t3: i16,
+    /// pressure compensation coefficient dig_P1
+    pub dig_p1: u16,
+    /// pressure compensation coefficient dig_P2
+    pub dig_p2: i16,
+    /// pressure compensation coefficient dig_P3
+    pub dig_p3: i16,
+    /// pressure compensation coefficient dig_P4
+    pub dig_p4: i16,
+    /// pressure compensation coefficient dig_P5
+    pub dig_p5: i16,
+    /// pressure compensation coefficient dig_P6
+    pub dig_p6: i16,
+    /// pressure compensation coefficient dig_P7
+    pub dig_p7: i16,
+    /// pressure compensation coefficient dig_P8
+    pub dig_p8: i16,
+    /// pressure compensation coefficient dig_P9
+    pub dig_p9: i16,
+    /// humidity compensation coefficient dig_H1
+    pub dig_h1: u8,
+    /// humidity compensation coefficient dig_H2
+    pub dig_h2: i16,
+    /// humidity compensation coefficient dig_H3
+    pub dig_h3: u8,
+    /// humidity compensation coefficient dig_H4
+    pub dig_h4: i16,
+    /// humidity compensation coefficient dig_H5
+    pub dig_h5: i16,
+    /// humidity compensation coefficient dig_H6
+    pub dig_h6: i8,
+    /// user temperature offset in hundredths of degrees celsius, e.g. -150 for -1.50 deg C.
+    /// It is applied to `t_fine`, so it also affects the pressure and humidity compensation.
+    /// Offsets beyond the width of the operating range, 125 deg C, are saturated.
+    pub temperature_offset: i32,
+    /// user humidity offset in hundredths of percent relative humidity
+    pub humidity_offset: i32,
+    /// user humidity gain in Q16.16 format, 65536 for a gain of 1.0.
+    /// The corrected humidity is `humidity * gain + offset`.
+    pub humidity_gain: u32,
+    /// user pressure offset in pascals, e.g. determined against a reference barometer
+    pub pressure_offset: i32,
     t_fine: i32,
 }
 
 /// Measurement data
+#[cfg(feature = "float")]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-#[cfg_attr(feature = "with_defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Measurements<E> {
     /// temperature in degrees celsius
     pub temperature: f32,
-    /// pressure in pascals
-    pub pressure: f32,
-    /// percent relative humidity (`0` with BMP280)
-    pub humidity: f32,
+    /// pressure in pascals (`None` if skipped)
+    pub pressure: Option<f32>,
+    /// percent relative humidity (`None` with BMP280 or if skipped)
+    pub humidity: Option<f32>,
     #[cfg_attr(feature = "serde", serde(skip))]
     _e: PhantomData<E>,
 }
 
+#[cfg(feature = "float")]
 impl<E> Debug for Measurements<E> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Measurements")
@@ -386,141 +1017,903 @@ impl<E> Debug for Measurements<E> {
     }
 }
 
-/// Type alias for future-proofing.
-pub type MeasurementsFloat<E> = Measurements<E>;
-
-/// Fixed-point measurement data in raw fixed point format
-#[cfg_attr(feature = "serde", derive(Serialize))]
-#[cfg_attr(feature = "with_defmt", derive(defmt::Format))]
-#[derive(Debug)]
-pub struct MeasurementsFixedRaw<E> {
-    /// temperature in hundreths of degrees celsius 2134 for 21.34 deg C
-    pub temperature: i32,
-    /// pressure in pascals in Q24.8 format
-    pub pressure: u32,
-    /// percent relative humidity in Q22.10 format (`0` with BMP280)
-    pub humidity: u32,
-    #[cfg_attr(feature = "serde", serde(skip))]
-    _e: PhantomData<E>,
+#[cfg(feature = "float")]
+impl<E> core::fmt::Display for Measurements<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::write!(f, "{:.2} °C", self.temperature)?;
+        if let Some(pressure) = self.pressure {
+            core::write!(f, ", {:.1} hPa", pressure / 100.0)?;
+        }
+        if let Some(humidity) = self.humidity {
+            core::write!(f, ", {humidity:.1} %RH")?;
+        }
+        Ok(())
+    }
 }
 
-/// Fixed-point measurement data in fixed point format
-#[cfg(feature = "fixed")]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct MeasurementsFixed<E> {
-    /// temperature in hundreths of degrees celsius 2134 for 21.34 deg C
-    pub temperature: i32,
-    /// pressure in pascals in Q24.8 format
-    pub pressure: ::fixed::types::U24F8,
-    /// percent relative humidity in Q22.10 format (`0` with BMP280)
-    pub humidity: ::fixed::types::U22F10,
-    #[cfg_attr(feature = "serde", serde(skip))]
-    _e: PhantomData<E>,
-}
+#[cfg(feature = "float")]
+impl<E> Measurements<E> {
+    /// Checks the values against the operating range of the sensor.
+    /// The compensation clamps to this range, so values at its limits are not plausible.
+    pub fn is_plausible(&self) -> bool {
+        BME280_TEMP_MIN < self.temperature
+            && self.temperature < BME280_TEMP_MAX
+            && self.pressure.is_none_or(|pressure| {
+                BME280_PRESSURE_MIN < pressure && pressure < BME280_PRESSURE_MAX
+            })
+            && self.humidity.is_none_or(|humidity| {
+                (BME280_HUMIDITY_MIN..=BME280_HUMIDITY_MAX).contains(&humidity)
+            })
+    }
 
-#[cfg(feature = "fixed")]
-impl<E> Debug for MeasurementsFixed<E> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let temp_int = self.temperature / 100;
-        let temp_frac = (self.temperature % 100).abs();
+    /// Returns the temperature in degrees fahrenheit
+    pub fn temperature_f(&self) -> f32 {
+        self.temperature * 1.8 + 32.0
+    }
 
-        core::write!(
-            f,
-            "MeasurementsFixed {{ temperature: {temp_int}.{temp_frac:02}, pressure: {:?}, humidity: {:?} }}",
-            self.pressure,
-            self.humidity
-        )
+    /// Returns the temperature in kelvin
+    pub fn temperature_k(&self) -> f32 {
+        self.temperature + 273.15
     }
-}
 
-trait Compensate<E, T, P, H>
-where
-    Self: Sized,
-{
-    fn parse(
-        data: [u8; BME280_P_T_H_DATA_LEN],
-        calibration: &mut CalibrationData,
-    ) -> Result<Self, Error<E>> {
-        let data_msb = (data[0] as u32) << 12;
-        let data_lsb = (data[1] as u32) << 4;
-        let data_xlsb = (data[2] as u32) >> 4;
-        let pressure = data_msb | data_lsb | data_xlsb;
+    /// Returns the pressure in hectopascals
+    pub fn pressure_hpa(&self) -> Option<f32> {
+        self.pressure.map(|pressure| pressure / 100.0)
+    }
 
-        let data_msb = (data[3] as u32) << 12;
-        let data_lsb = (data[4] as u32) << 4;
-        let data_xlsb = (data[5] as u32) >> 4;
-        let temperature = data_msb | data_lsb | data_xlsb;
+    /// Returns the pressure in inches of mercury
+    pub fn pressure_inhg(&self) -> Option<f32> {
+        self.pressure.map(|pressure| pressure / 3386.389)
+    }
 
-        let data_msb = (data[6] as u32) << 8;
-        let data_lsb = data[7] as u32;
-        let humidity = data_msb | data_lsb;
+    /// Returns the pressure in millimeters of mercury
+    pub fn pressure_mmhg(&self) -> Option<f32> {
+        self.pressure.map(|pressure| pressure / 133.322_37)
+    }
 
-        let temperature = Self::compensate_temperature(temperature, calibration)?;
-        let pressure = Self::compensate_pressure(pressure, calibration)?;
-        let humidity = Self::compensate_humidity(humidity, calibration)?;
+    /// Returns the pressure in whole hectopascals, rounded, as expected for the ambient pressure
+    /// compensation of e.g. the SCD30 and SCD4x CO2 sensors
+    pub fn ambient_pressure_hpa(&self) -> Option<u16> {
+        self.pressure
+            .map(|pressure| (pressure / 100.0 + 0.5) as u16)
+    }
 
-        Ok(Self::make(temperature, pressure, humidity))
+    /// Returns the pressure in whole pascals, rounded, as expected for the ambient pressure
+    /// compensation of e.g. the SPS30 particulate matter sensor
+    pub fn ambient_pressure_pa(&self) -> Option<u32> {
+        self.pressure.map(|pressure| (pressure + 0.5) as u32)
     }
 
-    fn make(temperature: T, pressure: P, humidity: H) -> Self;
-    fn compensate_temperature(
-        uncompensated: u32,
-        calibration: &mut CalibrationData,
-    ) -> Result<T, Error<E>>;
-    fn compensate_pressure(
-        uncompensated: u32,
-        calibration: &CalibrationData,
-    ) -> Result<P, Error<E>>;
-    fn compensate_humidity(
-        uncompensated: u32,
-        calibration: &CalibrationData,
-    ) -> Result<H, Error<E>>;
-}
+    /// Computes the altitude in meters for the given sea level pressure in pascals.
+    /// See [`derived::altitude_m`].
+    pub fn altitude_m(&self, sea_level_pressure: f32) -> Option<f32> {
+        self.pressure
+            .map(|pressure| derived::altitude_m(pressure, sea_level_pressure))
+    }
 
-impl<E> Compensate<E, f32, f32, f32> for Measurements<E> {
-    fn make(temperature: f32, pressure: f32, humidity: f32) -> Self {
-        Measurements {
-            temperature,
-            pressure,
-            humidity,
-            _e: PhantomData,
-        }
+    /// Computes the sea level pressure in pascals for a station at the given altitude in meters.
+    /// See [`derived::sea_level_pressure`].
+    pub fn sea_level_pressure(&self, altitude_m: f32) -> Option<f32> {
+        self.pressure
+            .map(|pressure| derived::sea_level_pressure(pressure, altitude_m))
     }
 
-    fn compensate_temperature(
-        uncompensated: u32,
-        calibration: &mut CalibrationData,
-    ) -> Result<f32, Error<E>> {
-        let var1 = uncompensated as f32 / 16384.0 - calibration.dig_t1 as f32 / 1024.0;
-        let var1 = var1 * calibration.dig_t2 as f32;
-        let var2 = uncompensated as f32 / 131072.0 - calibration.dig_t1 as f32 / 8192.0;
-        let var2 = var2 * var2 * calibration.dig_t3 as f32;
+    /// Computes the dew point in degrees celsius.
+    /// See [`derived::dew_point`].
+    pub fn dew_point(&self) -> Option<f32> {
+        self.humidity
+            .map(|humidity| derived::dew_point(self.temperature, humidity))
+    }
 
-        calibration.t_fine = (var1 + var2) as i32;
+    /// Computes the frost point in degrees celsius.
+    /// See [`derived::frost_point`].
+    pub fn frost_point(&self) -> Option<f32> {
+        self.humidity
+            .map(|humidity| derived::frost_point(self.temperature, humidity))
+    }
 
-        let temperature = (var1 + var2) / 5120.0;
-        let temperature = temperature.clamp(BME280_TEMP_MIN, BME280_TEMP_MAX);
-        Ok(temperature)
+    /// Computes the vapor pressure deficit in kilopascals.
+    /// See [`derived::vapor_pressure_deficit`].
+    pub fn vapor_pressure_deficit(&self) -> Option<f32> {
+        self.humidity
+            .map(|humidity| derived::vapor_pressure_deficit(self.temperature, humidity))
     }
 
-    fn compensate_pressure(
-        uncompensated: u32,
-        calibration: &CalibrationData,
-    ) -> Result<f32, Error<E>> {
-        let var1 = calibration.t_fine as f32 / 2.0 - 64000.0;
-        let var2 = var1 * var1 * calibration.dig_p6 as f32 / 32768.0;
-        let var2 = var2 + var1 * calibration.dig_p5 as f32 * 2.0;
-        let var2 = var2 / 4.0 + calibration.dig_p4 as f32 * 65536.0;
-        let var3 = calibration.dig_p3 as f32 * var1 * var1 / 524288.0;
-        let var1 = (var3 + calibration.dig_p2 as f32 * var1) / 524288.0;
-        let var1 = (1.0 + var1 / 32768.0) * calibration.dig_p1 as f32;
+    /// Computes the absolute humidity in grams per cubic meter.
+    /// See [`derived::absolute_humidity`].
+    pub fn absolute_humidity(&self) -> Option<f32> {
+        Some(derived::absolute_humidity(
+            self.temperature,
+            self.humidity?,
+            self.pressure?,
+        ))
+    }
 
-        let pressure = if var1 > 0.0 {
+    /// Computes the heat index in degrees celsius.
+    /// See [`derived::heat_index`].
+    pub fn heat_index(&self) -> Option<f32> {
+        self.humidity
+            .map(|humidity| derived::heat_index(self.temperature, humidity))
+    }
+
+    /// Computes the humidex in degrees celsius.
+    /// See [`derived::humidex`].
+    pub fn humidex(&self) -> Option<f32> {
+        self.humidity
+            .map(|humidity| derived::humidex(self.temperature, humidity))
+    }
+
+    /// Computes the wet-bulb temperature in degrees celsius.
+    /// See [`derived::wet_bulb_temperature`].
+    pub fn wet_bulb_temperature(&self) -> Option<f32> {
+        Some(derived::wet_bulb_temperature(
+            self.temperature,
+            self.humidity?,
+            self.pressure?,
+        ))
+    }
+
+    /// Computes the specific enthalpy in kilojoules per kilogram of dry air.
+    /// See [`derived::enthalpy`].
+    pub fn enthalpy(&self) -> Option<f32> {
+        Some(derived::enthalpy(
+            self.temperature,
+            self.humidity?,
+            self.pressure?,
+        ))
+    }
+}
+
+#[cfg(feature = "json")]
+impl<E> Measurements<E> {
+    /// Serializes the measurement as a JSON object with the keys `temperature_c`, `pressure_pa`
+    /// and `humidity_pct`. Skipped values are `null`.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{");
+        push_json_field(&mut json, "temperature_c", Some(self.temperature));
+        push_json_field(&mut json, "pressure_pa", self.pressure);
+        push_json_field(&mut json, "humidity_pct", self.humidity);
+        json.pop();
+        json.push('}');
+        json
+    }
+
+    /// Like [`Self::to_json`], with the derived values `dew_point_c`, `absolute_humidity_g_m3`
+    /// and `heat_index_c` added. Derived values are `null` if their inputs were skipped.
+    pub fn to_json_with_derived(&self) -> String {
+        let mut json = self.to_json();
+        json.pop();
+        json.push(',');
+        push_json_field(&mut json, "dew_point_c", self.dew_point());
+        push_json_field(
+            &mut json,
+            "absolute_humidity_g_m3",
+            self.absolute_humidity(),
+        );
+        push_json_field(&mut json, "heat_index_c", self.heat_index());
+        json.pop();
+        json.push('}');
+        json
+    }
+}
+
+/// Appends `"key":value,` to a JSON object, with `null` for missing and non-finite values
+#[cfg(feature = "json")]
+fn push_json_field(json: &mut String, key: &str, value: Option<f32>) {
+    match value.filter(|value| value.is_finite()) {
+        Some(value) => json.push_str(&format!("\"{key}\":{value},")),
+        None => json.push_str(&format!("\"{key}\":null,")),
+    }
+}
+
+/// Type alias for future-proofing.
+#[cfg(feature = "float")]
+pub type MeasurementsFloat<E> = Measurements<E>;
+
+/// Measurement data computed with the double precision compensation formulas
+#[cfg(feature = "double-precision")]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MeasurementsDouble<E> {
+    /// temperature in degrees celsius
+    pub temperature: f64,
+    /// pressure in pascals (`None` if skipped)
+    pub pressure: Option<f64>,
+    /// percent relative humidity (`None` with BMP280 or if skipped)
+    pub humidity: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _e: PhantomData<E>,
+}
+
+#[cfg(feature = "double-precision")]
+impl<E> Debug for MeasurementsDouble<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MeasurementsDouble")
+            .field("temperature", &self.temperature)
+            .field("pressure", &self.pressure)
+            .field("humidity", &self.humidity)
+            .finish()
+    }
+}
+
+#[cfg(feature = "double-precision")]
+impl<E> MeasurementsDouble<E> {
+    /// Returns the pressure in whole hectopascals, rounded, as expected for the ambient pressure
+    /// compensation of e.g. the SCD30 and SCD4x CO2 sensors
+    pub fn ambient_pressure_hpa(&self) -> Option<u16> {
+        self.pressure
+            .map(|pressure| (pressure / 100.0 + 0.5) as u16)
+    }
+
+    /// Returns the pressure in whole pascals, rounded, as expected for the ambient pressure
+    /// compensation of e.g. the SPS30 particulate matter sensor
+    pub fn ambient_pressure_pa(&self) -> Option<u32> {
+        self.pressure.map(|pressure| (pressure + 0.5) as u32)
+    }
+
+    /// Checks the values against the operating range of the sensor.
+    /// The compensation clamps to this range, so values at its limits are not plausible.
+    pub fn is_plausible(&self) -> bool {
+        f64::from(BME280_TEMP_MIN) < self.temperature
+            && self.temperature < f64::from(BME280_TEMP_MAX)
+            && self.pressure.is_none_or(|pressure| {
+                f64::from(BME280_PRESSURE_MIN) < pressure
+                    && pressure < f64::from(BME280_PRESSURE_MAX)
+            })
+            && self.humidity.is_none_or(|humidity| {
+                (f64::from(BME280_HUMIDITY_MIN)..=f64::from(BME280_HUMIDITY_MAX))
+                    .contains(&humidity)
+            })
+    }
+}
+
+#[cfg(all(feature = "float", feature = "ufmt"))]
+impl<E> ufmt::uDisplay for Measurements<E> {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        ufmt_measurements(
+            f,
+            Decimal::from_f64(f64::from(self.temperature), 2),
+            self.pressure
+                .map(|pressure| Decimal::from_f64(f64::from(pressure) / 100.0, 1)),
+            self.humidity
+                .map(|humidity| Decimal::from_f64(f64::from(humidity), 1)),
+        )
+    }
+}
+
+#[cfg(all(feature = "double-precision", feature = "ufmt"))]
+impl<E> ufmt::uDisplay for MeasurementsDouble<E> {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        ufmt_measurements(
+            f,
+            Decimal::from_f64(self.temperature, 2),
+            self.pressure
+                .map(|pressure| Decimal::from_f64(pressure / 100.0, 1)),
+            self.humidity.map(|humidity| Decimal::from_f64(humidity, 1)),
+        )
+    }
+}
+
+#[cfg(feature = "double-precision")]
+impl<E> core::fmt::Display for MeasurementsDouble<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::write!(f, "{:.2} °C", self.temperature)?;
+        if let Some(pressure) = self.pressure {
+            core::write!(f, ", {:.1} hPa", pressure / 100.0)?;
+        }
+        if let Some(humidity) = self.humidity {
+            core::write!(f, ", {humidity:.1} %RH")?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts a pressure in pascals in Q24.8 format to hectopascals with two decimals
+fn pressure_hpa_decimal(pressure: u32) -> Decimal {
+    Decimal::from_fixed(i64::from(pressure), 8, 0).shifted(2)
+}
+
+/// Converts a percent relative humidity in Q22.10 format to a decimal with two decimals
+fn humidity_decimal(humidity: u32) -> Decimal {
+    Decimal::from_fixed(i64::from(humidity), 10, 2)
+}
+
+/// Rounds a Q24.8 pressure to whole pascals
+fn ambient_pressure_pa(pressure: u32) -> u32 {
+    (pressure >> 8) + ((pressure >> 7) & 1)
+}
+
+/// Rounds a Q24.8 pressure to whole hectopascals
+fn ambient_pressure_hpa(pressure: u32) -> u16 {
+    ((ambient_pressure_pa(pressure) + 50) / 100) as u16
+}
+
+#[cfg(feature = "ufmt")]
+fn ufmt_measurements<W: ufmt::uWrite + ?Sized>(
+    f: &mut ufmt::Formatter<'_, W>,
+    temperature: Decimal,
+    pressure: Option<Decimal>,
+    humidity: Option<Decimal>,
+) -> Result<(), W::Error> {
+    ufmt::uwrite!(f, "{} °C", temperature)?;
+    if let Some(pressure) = pressure {
+        ufmt::uwrite!(f, ", {} hPa", pressure)?;
+    }
+    if let Some(humidity) = humidity {
+        ufmt::uwrite!(f, ", {} %RH", humidity)?;
+    }
+    Ok(())
+}
+
+/// Checks integer values against the operating range of the sensor
+fn is_plausible_fixed(temperature: i32, pressure: Option<u32>, humidity: Option<u32>) -> bool {
+    BME280_TEMP_MIN_FIXED < temperature
+        && temperature < BME280_TEMP_MAX_FIXED
+        && pressure.is_none_or(|pressure| {
+            BME280_PRESSURE_MIN_FIXED < pressure && pressure < BME280_PRESSURE_MAX_FIXED
+        })
+        && humidity.is_none_or(|humidity| {
+            (BME280_HUMIDITY_MIN_FIXED..=BME280_HUMIDITY_MAX_FIXED).contains(&humidity)
+        })
+}
+
+/// Formats measurements in the integer formats with integer math only, rounding pressure and
+/// humidity to one decimal
+fn fmt_fixed(
+    f: &mut core::fmt::Formatter<'_>,
+    temperature: i32,
+    pressure: Option<u32>,
+    humidity: Option<u32>,
+) -> core::fmt::Result {
+    core::write!(f, "{} °C", Decimal::from_hundredths(temperature))?;
+    if let Some(pressure) = pressure {
+        core::write!(f, ", {} hPa", pressure_hpa_decimal(pressure).rounded(1))?;
+    }
+    if let Some(humidity) = humidity {
+        core::write!(f, ", {} %RH", humidity_decimal(humidity).rounded(1))?;
+    }
+    Ok(())
+}
+
+/// Fixed-point measurement data in raw fixed point format.
+/// The values use the formats of the Bosch reference driver and are computed with integer math
+/// only, which suits cores without an FPU.
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug)]
+pub struct MeasurementsFixedRaw<E> {
+    /// temperature in hundreths of degrees celsius 2134 for 21.34 deg C
+    pub temperature: i32,
+    /// pressure in pascals in Q24.8 format (`None` if skipped)
+    pub pressure: Option<u32>,
+    /// percent relative humidity in Q22.10 format (`None` with BMP280 or if skipped)
+    pub humidity: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _e: PhantomData<E>,
+}
+
+impl<E> core::fmt::Display for MeasurementsFixedRaw<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_fixed(f, self.temperature, self.pressure, self.humidity)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<E> ufmt::uDisplay for MeasurementsFixedRaw<E> {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        ufmt_measurements(
+            f,
+            Decimal::from_hundredths(self.temperature),
+            self.pressure
+                .map(|pressure| pressure_hpa_decimal(pressure).rounded(1)),
+            self.humidity
+                .map(|humidity| humidity_decimal(humidity).rounded(1)),
+        )
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<E> ufmt::uDebug for MeasurementsFixedRaw<E> {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        f.debug_struct("MeasurementsFixedRaw")?
+            .field("temperature", &self.temperature)?
+            .field("pressure", &self.pressure)?
+            .field("humidity", &self.humidity)?
+            .finish()
+    }
+}
+
+impl<E> MeasurementsFixedRaw<E> {
+    /// Checks the values against the operating range of the sensor.
+    /// The compensation clamps to this range, so values at its limits are not plausible.
+    pub fn is_plausible(&self) -> bool {
+        is_plausible_fixed(self.temperature, self.pressure, self.humidity)
+    }
+
+    /// Returns the temperature in degrees celsius as a [`Decimal`] with two decimals
+    pub fn temperature_decimal(&self) -> Decimal {
+        Decimal::from_hundredths(self.temperature)
+    }
+
+    /// Returns the pressure in hectopascals as a [`Decimal`] with two decimals
+    pub fn pressure_hpa_decimal(&self) -> Option<Decimal> {
+        self.pressure.map(pressure_hpa_decimal)
+    }
+
+    /// Returns the pressure in whole hectopascals, rounded, as expected for the ambient pressure
+    /// compensation of e.g. the SCD30 and SCD4x CO2 sensors
+    pub fn ambient_pressure_hpa(&self) -> Option<u16> {
+        self.pressure.map(ambient_pressure_hpa)
+    }
+
+    /// Returns the pressure in whole pascals, rounded, as expected for the ambient pressure
+    /// compensation of e.g. the SPS30 particulate matter sensor
+    pub fn ambient_pressure_pa(&self) -> Option<u32> {
+        self.pressure.map(ambient_pressure_pa)
+    }
+
+    /// Returns the percent relative humidity as a [`Decimal`] with two decimals
+    pub fn humidity_decimal(&self) -> Option<Decimal> {
+        self.humidity.map(humidity_decimal)
+    }
+
+    /// Computes the sea level pressure in pascals in Q24.8 format for a station at the given
+    /// altitude in meters.
+    /// See [`derived::sea_level_pressure_fixed`].
+    pub fn sea_level_pressure(&self, altitude_m: i32) -> Option<u32> {
+        self.pressure
+            .map(|pressure| derived::sea_level_pressure_fixed(pressure, altitude_m))
+    }
+
+    /// Computes the dew point in hundredths of degrees celsius.
+    /// See [`derived::dew_point_fixed`].
+    pub fn dew_point(&self) -> Option<i32> {
+        self.humidity
+            .map(|humidity| derived::dew_point_fixed(self.temperature, humidity))
+    }
+
+    /// Computes the vapor pressure deficit in pascals.
+    /// See [`derived::vapor_pressure_deficit_fixed`].
+    pub fn vapor_pressure_deficit(&self) -> Option<u32> {
+        self.humidity
+            .map(|humidity| derived::vapor_pressure_deficit_fixed(self.temperature, humidity))
+    }
+}
+
+/// Fixed-point measurement data in fixed point format
+#[cfg(feature = "fixed")]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MeasurementsFixed<E> {
+    /// temperature in hundreths of degrees celsius 2134 for 21.34 deg C
+    pub temperature: i32,
+    /// pressure in pascals in Q24.8 format (`None` if skipped)
+    pub pressure: Option<::fixed::types::U24F8>,
+    /// percent relative humidity in Q22.10 format (`None` with BMP280 or if skipped)
+    pub humidity: Option<::fixed::types::U22F10>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _e: PhantomData<E>,
+}
+
+#[cfg(feature = "fixed")]
+impl<E> Debug for MeasurementsFixed<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let temp_int = self.temperature / 100;
+        let temp_frac = (self.temperature % 100).abs();
+
+        core::write!(
+            f,
+            "MeasurementsFixed {{ temperature: {temp_int}.{temp_frac:02}, pressure: {:?}, humidity: {:?} }}",
+            self.pressure,
+            self.humidity
+        )
+    }
+}
+
+#[cfg(all(feature = "fixed", feature = "defmt"))]
+impl<E> Format for MeasurementsFixed<E> {
+    fn format(&self, fmt: Formatter) {
+        write!(
+            fmt,
+            "MeasurementsFixed {{ temperature: {}, pressure: {}, humidity: {} }}",
+            Decimal::from_hundredths(self.temperature),
+            self.pressure
+                .map(|pressure| Decimal::from_fixed(i64::from(pressure.to_bits()), 8, 2)),
+            self.humidity
+                .map(|humidity| Decimal::from_fixed(i64::from(humidity.to_bits()), 10, 3)),
+        )
+    }
+}
+
+#[cfg(all(feature = "fixed", feature = "ufmt"))]
+impl<E> ufmt::uDisplay for MeasurementsFixed<E> {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        ufmt_measurements(
+            f,
+            Decimal::from_hundredths(self.temperature),
+            self.pressure
+                .map(|pressure| pressure_hpa_decimal(pressure.to_bits()).rounded(1)),
+            self.humidity
+                .map(|humidity| humidity_decimal(humidity.to_bits()).rounded(1)),
+        )
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl<E> core::fmt::Display for MeasurementsFixed<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_fixed(
+            f,
+            self.temperature,
+            self.pressure.map(|pressure| pressure.to_bits()),
+            self.humidity.map(|humidity| humidity.to_bits()),
+        )
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl<E> MeasurementsFixed<E> {
+    /// Checks the values against the operating range of the sensor.
+    /// The compensation clamps to this range, so values at its limits are not plausible.
+    pub fn is_plausible(&self) -> bool {
+        is_plausible_fixed(
+            self.temperature,
+            self.pressure.map(|pressure| pressure.to_bits()),
+            self.humidity.map(|humidity| humidity.to_bits()),
+        )
+    }
+
+    /// Returns the pressure in whole hectopascals, rounded, as expected for the ambient pressure
+    /// compensation of e.g. the SCD30 and SCD4x CO2 sensors
+    pub fn ambient_pressure_hpa(&self) -> Option<u16> {
+        self.pressure
+            .map(|pressure| ambient_pressure_hpa(pressure.to_bits()))
+    }
+
+    /// Returns the pressure in whole pascals, rounded, as expected for the ambient pressure
+    /// compensation of e.g. the SPS30 particulate matter sensor
+    pub fn ambient_pressure_pa(&self) -> Option<u32> {
+        self.pressure
+            .map(|pressure| ambient_pressure_pa(pressure.to_bits()))
+    }
+
+    /// Computes the sea level pressure in pascals for a station at the given altitude in meters.
+    /// See [`derived::sea_level_pressure_fixed`].
+    pub fn sea_level_pressure(&self, altitude_m: i32) -> Option<::fixed::types::U24F8> {
+        self.pressure.map(|pressure| {
+            ::fixed::types::U24F8::from_bits(derived::sea_level_pressure_fixed(
+                pressure.to_bits(),
+                altitude_m,
+            ))
+        })
+    }
+
+    /// Computes the dew point in hundredths of degrees celsius.
+    /// See [`derived::dew_point_fixed`].
+    pub fn dew_point(&self) -> Option<i32> {
+        self.humidity
+            .map(|humidity| derived::dew_point_fixed(self.temperature, humidity.to_bits()))
+    }
+
+    /// Computes the vapor pressure deficit in pascals.
+    /// See [`derived::vapor_pressure_deficit_fixed`].
+    pub fn vapor_pressure_deficit(&self) -> Option<u32> {
+        self.humidity.map(|humidity| {
+            derived::vapor_pressure_deficit_fixed(self.temperature, humidity.to_bits())
+        })
+    }
+}
+
+/// Uncompensated ADC values, as read from the data registers.
+/// Temperature and pressure are 20-bit values and humidity is a 16-bit value. A skipped
+/// measurement reads as `0x80000` (`0x8000` for humidity), which is also what a BMP280 reports
+/// for humidity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct RawMeasurements {
+    /// raw temperature ADC value
+    pub temperature: u32,
+    /// raw pressure ADC value
+    pub pressure: u32,
+    /// raw humidity ADC value
+    pub humidity: u32,
+}
+
+impl RawMeasurements {
+    fn from_temperature_data(data: [u8; BME280_T_DATA_LEN]) -> Self {
+        let data_msb = (data[0] as u32) << 12;
+        let data_lsb = (data[1] as u32) << 4;
+        let data_xlsb = (data[2] as u32) >> 4;
+
+        Self {
+            temperature: data_msb | data_lsb | data_xlsb,
+            pressure: BME280_SKIPPED_PRESSURE,
+            humidity: BME280_SKIPPED_HUMIDITY,
+        }
+    }
+
+    fn from_pt_data(data: [u8; BME280_P_T_DATA_LEN]) -> Self {
+        let data_msb = (data[0] as u32) << 12;
+        let data_lsb = (data[1] as u32) << 4;
+        let data_xlsb = (data[2] as u32) >> 4;
+        let pressure = data_msb | data_lsb | data_xlsb;
+
+        let data_msb = (data[3] as u32) << 12;
+        let data_lsb = (data[4] as u32) << 4;
+        let data_xlsb = (data[5] as u32) >> 4;
+        let temperature = data_msb | data_lsb | data_xlsb;
+
+        Self {
+            temperature,
+            pressure,
+            humidity: BME280_SKIPPED_HUMIDITY,
+        }
+    }
+
+    fn from_data(data: [u8; BME280_P_T_H_DATA_LEN], has_humidity: bool) -> Self {
+        let [p_msb, p_lsb, p_xlsb, t_msb, t_lsb, t_xlsb, h_msb, h_lsb] = data;
+        let mut raw = Self::from_pt_data([p_msb, p_lsb, p_xlsb, t_msb, t_lsb, t_xlsb]);
+
+        if has_humidity {
+            let data_msb = (h_msb as u32) << 8;
+            let data_lsb = h_lsb as u32;
+            raw.humidity = data_msb | data_lsb;
+        }
+        raw
+    }
+}
+
+mod sealed {
+    use super::{CalibrationData, Error, RawMeasurements};
+
+    pub trait Sealed<E>: Sized {
+        fn compensate(
+            raw: &RawMeasurements,
+            calibration: &mut CalibrationData,
+        ) -> Result<Self, Error<E>>;
+        fn compensate_with_t_fine(
+            raw: &RawMeasurements,
+            calibration: &mut CalibrationData,
+            t_fine: i32,
+        ) -> Result<Self, Error<E>>;
+    }
+}
+
+/// Output format of the compensated measurements, to be used with the generic `measure_as()`
+/// and `read_measurement_as()` driver methods.
+/// This is implemented by [`Measurements`], [`MeasurementsFixedRaw`], `MeasurementsFixed` and
+/// `MeasurementsFixedQ` (`fixed` feature) and `MeasurementsDouble` (`double-precision` feature).
+pub trait MeasurementFormat<E>: sealed::Sealed<E> {}
+
+macro_rules! impl_measurement_format {
+    ($format:ident) => {
+        impl<E> sealed::Sealed<E> for $format<E> {
+            fn compensate(
+                raw: &RawMeasurements,
+                calibration: &mut CalibrationData,
+            ) -> Result<Self, Error<E>> {
+                Compensate::compensate(raw, calibration)
+            }
+
+            fn compensate_with_t_fine(
+                raw: &RawMeasurements,
+                calibration: &mut CalibrationData,
+                t_fine: i32,
+            ) -> Result<Self, Error<E>> {
+                Compensate::compensate_with_t_fine(raw, calibration, t_fine)
+            }
+        }
+
+        impl<E> MeasurementFormat<E> for $format<E> {}
+    };
+}
+
+#[cfg(feature = "float")]
+impl_measurement_format!(Measurements);
+impl_measurement_format!(MeasurementsFixedRaw);
+#[cfg(feature = "fixed")]
+impl_measurement_format!(MeasurementsFixed);
+#[cfg(feature = "double-precision")]
+impl_measurement_format!(MeasurementsDouble);
+
+/// Fixed-point measurement data in Q formats chosen by the caller, e.g.
+/// `MeasurementsFixedQ<E, I20F12, U24F8, U22F10>`.
+/// The values are computed with the integer compensation formulas and converted with
+/// saturation, so formats with too few integer bits clamp instead of wrapping.
+#[cfg(feature = "fixed")]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug)]
+pub struct MeasurementsFixedQ<E, T, P, H> {
+    /// temperature in degrees celsius
+    pub temperature: T,
+    /// pressure in pascals (`None` if skipped)
+    pub pressure: Option<P>,
+    /// percent relative humidity (`None` with BMP280 or if skipped)
+    pub humidity: Option<H>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _e: PhantomData<E>,
+}
+
+#[cfg(feature = "fixed")]
+impl<E, T: ::fixed::traits::Fixed, P: ::fixed::traits::Fixed, H: ::fixed::traits::Fixed>
+    sealed::Sealed<E> for MeasurementsFixedQ<E, T, P, H>
+{
+    fn compensate(
+        raw: &RawMeasurements,
+        calibration: &mut CalibrationData,
+    ) -> Result<Self, Error<E>> {
+        Ok(Self::from_fixed_raw(Compensate::compensate(
+            raw,
+            calibration,
+        )?))
+    }
+
+    fn compensate_with_t_fine(
+        raw: &RawMeasurements,
+        calibration: &mut CalibrationData,
+        t_fine: i32,
+    ) -> Result<Self, Error<E>> {
+        Ok(Self::from_fixed_raw(Compensate::compensate_with_t_fine(
+            raw,
+            calibration,
+            t_fine,
+        )?))
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl<E, T: ::fixed::traits::Fixed, P: ::fixed::traits::Fixed, H: ::fixed::traits::Fixed>
+    MeasurementsFixedQ<E, T, P, H>
+{
+    fn from_fixed_raw(measurements: MeasurementsFixedRaw<E>) -> Self {
+        let temperature = ::fixed::types::I32F32::from_num(measurements.temperature) / 100;
+
+        MeasurementsFixedQ {
+            temperature: T::saturating_from_num(temperature),
+            pressure: measurements
+                .pressure
+                .map(|pressure| P::saturating_from_num(::fixed::types::U24F8::from_bits(pressure))),
+            humidity: measurements.humidity.map(|humidity| {
+                H::saturating_from_num(::fixed::types::U22F10::from_bits(humidity))
+            }),
+            _e: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl<E, T: ::fixed::traits::Fixed, P: ::fixed::traits::Fixed, H: ::fixed::traits::Fixed>
+    MeasurementFormat<E> for MeasurementsFixedQ<E, T, P, H>
+{
+}
+
+trait Compensate<E, T, P, H>
+where
+    Self: Sized,
+{
+    fn compensate(
+        raw: &RawMeasurements,
+        calibration: &mut CalibrationData,
+    ) -> Result<Self, Error<E>> {
+        if raw.temperature == BME280_SKIPPED_TEMPERATURE {
+            return Err(Error::InvalidData);
+        }
+        let temperature = Self::compensate_temperature(raw.temperature, calibration)?;
+        Self::compensate_pressure_humidity(temperature, raw, calibration)
+    }
+
+    /// Compensates using the `t_fine` of a previous measurement instead of the raw temperature
+    fn compensate_with_t_fine(
+        raw: &RawMeasurements,
+        calibration: &mut CalibrationData,
+        t_fine: i32,
+    ) -> Result<Self, Error<E>> {
+        calibration.t_fine = t_fine;
+        let temperature = Self::temperature_from_t_fine(t_fine);
+        Self::compensate_pressure_humidity(temperature, raw, calibration)
+    }
+
+    fn compensate_pressure_humidity(
+        temperature: T,
+        raw: &RawMeasurements,
+        calibration: &CalibrationData,
+    ) -> Result<Self, Error<E>> {
+        let pressure = match raw.pressure {
+            BME280_SKIPPED_PRESSURE => None,
+            pressure => Some(Self::compensate_pressure(pressure, calibration)?),
+        };
+        let humidity = match raw.humidity {
+            BME280_SKIPPED_HUMIDITY => None,
+            humidity => Some(Self::compensate_humidity(humidity, calibration)?),
+        };
+
+        Ok(Self::make(temperature, pressure, humidity))
+    }
+
+    fn make(temperature: T, pressure: Option<P>, humidity: Option<H>) -> Self;
+    fn temperature_from_t_fine(t_fine: i32) -> T;
+    fn compensate_temperature(
+        uncompensated: u32,
+        calibration: &mut CalibrationData,
+    ) -> Result<T, Error<E>>;
+    fn compensate_pressure(
+        uncompensated: u32,
+        calibration: &CalibrationData,
+    ) -> Result<P, Error<E>>;
+    fn compensate_humidity(
+        uncompensated: u32,
+        calibration: &CalibrationData,
+    ) -> Result<H, Error<E>>;
+}
+
+#[cfg(feature = "float")]
+impl<E> Compensate<E, f32, f32, f32> for Measurements<E> {
+    fn make(temperature: f32, pressure: Option<f32>, humidity: Option<f32>) -> Self {
+        Measurements {
+            temperature,
+            pressure,
+            humidity,
+            _e: PhantomData,
+        }
+    }
+
+    fn compensate_temperature(
+        uncompensated: u32,
+        calibration: &mut CalibrationData,
+    ) -> Result<f32, Error<E>> {
+        let var1 = uncompensated as f32 / 16384.0 - calibration.dig_t1 as f32 / 1024.0;
+        let var1 = var1 * calibration.dig_t2 as f32;
+        let var2 = uncompensated as f32 / 131072.0 - calibration.dig_t1 as f32 / 8192.0;
+        let var2 = var2 * var2 * calibration.dig_t3 as f32;
+        let t_fine = var1 + var2 + calibration.clamped_temperature_offset() as f32 * 51.2;
+
+        calibration.t_fine = t_fine as i32;
+
+        let temperature = t_fine / 5120.0;
+        let temperature = temperature.clamp(BME280_TEMP_MIN, BME280_TEMP_MAX);
+        Ok(temperature)
+    }
+
+    fn temperature_from_t_fine(t_fine: i32) -> f32 {
+        let temperature = t_fine as f32 / 5120.0;
+        temperature.clamp(BME280_TEMP_MIN, BME280_TEMP_MAX)
+    }
+
+    fn compensate_pressure(
+        uncompensated: u32,
+        calibration: &CalibrationData,
+    ) -> Result<f32, Error<E>> {
+        let var1 = calibration.t_fine as f32 / 2.0 - 64000.0;
+        let var2 = var1 * var1 * calibration.dig_p6 as f32 / 32768.0;
+        let var2 = var2 + var1 * calibration.dig_p5 as f32 * 2.0;
+        let var2 = var2 / 4.0 + calibration.dig_p4 as f32 * 65536.0;
+        let var3 = calibration.dig_p3 as f32 * var1 * var1 / 524288.0;
+        let var1 = (var3 + calibration.dig_p2 as f32 * var1) / 524288.0;
+        let var1 = (1.0 + var1 / 32768.0) * calibration.dig_p1 as f32;
+
+        let pressure = if var1 > 0.0 {
             let pressure = 1048576.0 - uncompensated as f32;
             let pressure = (pressure - (var2 / 4096.0)) * 6250.0 / var1;
             let var1 = calibration.dig_p9 as f32 * pressure * pressure / 2147483648.0;
             let var2 = pressure * calibration.dig_p8 as f32 / 32768.0;
             let pressure = pressure + (var1 + var2 + calibration.dig_p7 as f32) / 16.0;
+            let pressure = pressure + calibration.pressure_offset as f32;
             pressure.clamp(BME280_PRESSURE_MIN, BME280_PRESSURE_MAX)
         } else {
             return Err(Error::InvalidData);
@@ -541,17 +1934,104 @@ impl<E> Compensate<E, f32, f32, f32> for Measurements<E> {
         let var6 = var3 * var4 * (var5 * var6);
 
         let humidity = var6 * (1.0 - calibration.dig_h1 as f32 * var6 / 524288.0);
+        let humidity = humidity * calibration.humidity_gain as f32 / 65536.0
+            + calibration.humidity_offset as f32 / 100.0;
         let humidity = humidity.clamp(BME280_HUMIDITY_MIN, BME280_HUMIDITY_MAX);
         Ok(humidity)
     }
 }
 
+#[cfg(feature = "double-precision")]
+impl<E> Compensate<E, f64, f64, f64> for MeasurementsDouble<E> {
+    fn make(temperature: f64, pressure: Option<f64>, humidity: Option<f64>) -> Self {
+        MeasurementsDouble {
+            temperature,
+            pressure,
+            humidity,
+            _e: PhantomData,
+        }
+    }
+
+    fn compensate_temperature(
+        uncompensated: u32,
+        calibration: &mut CalibrationData,
+    ) -> Result<f64, Error<E>> {
+        let var1 = uncompensated as f64 / 16384.0 - calibration.dig_t1 as f64 / 1024.0;
+        let var1 = var1 * calibration.dig_t2 as f64;
+        let var2 = uncompensated as f64 / 131072.0 - calibration.dig_t1 as f64 / 8192.0;
+        let var2 = var2 * var2 * calibration.dig_t3 as f64;
+        let t_fine = var1 + var2 + calibration.clamped_temperature_offset() as f64 * 51.2;
+
+        calibration.t_fine = t_fine as i32;
+
+        let temperature = t_fine / 5120.0;
+        let temperature = temperature.clamp(f64::from(BME280_TEMP_MIN), f64::from(BME280_TEMP_MAX));
+        Ok(temperature)
+    }
+
+    fn temperature_from_t_fine(t_fine: i32) -> f64 {
+        let temperature = f64::from(t_fine) / 5120.0;
+        temperature.clamp(f64::from(BME280_TEMP_MIN), f64::from(BME280_TEMP_MAX))
+    }
+
+    fn compensate_pressure(
+        uncompensated: u32,
+        calibration: &CalibrationData,
+    ) -> Result<f64, Error<E>> {
+        let var1 = calibration.t_fine as f64 / 2.0 - 64000.0;
+        let var2 = var1 * var1 * calibration.dig_p6 as f64 / 32768.0;
+        let var2 = var2 + var1 * calibration.dig_p5 as f64 * 2.0;
+        let var2 = var2 / 4.0 + calibration.dig_p4 as f64 * 65536.0;
+        let var3 = calibration.dig_p3 as f64 * var1 * var1 / 524288.0;
+        let var1 = (var3 + calibration.dig_p2 as f64 * var1) / 524288.0;
+        let var1 = (1.0 + var1 / 32768.0) * calibration.dig_p1 as f64;
+
+        let pressure = if var1 > 0.0 {
+            let pressure = 1048576.0 - uncompensated as f64;
+            let pressure = (pressure - (var2 / 4096.0)) * 6250.0 / var1;
+            let var1 = calibration.dig_p9 as f64 * pressure * pressure / 2147483648.0;
+            let var2 = pressure * calibration.dig_p8 as f64 / 32768.0;
+            let pressure = pressure + (var1 + var2 + calibration.dig_p7 as f64) / 16.0;
+            let pressure = pressure + f64::from(calibration.pressure_offset);
+            pressure.clamp(
+                f64::from(BME280_PRESSURE_MIN),
+                f64::from(BME280_PRESSURE_MAX),
+            )
+        } else {
+            return Err(Error::InvalidData);
+        };
+        Ok(pressure)
+    }
+
+    fn compensate_humidity(
+        uncompensated: u32,
+        calibration: &CalibrationData,
+    ) -> Result<f64, Error<E>> {
+        let var1 = calibration.t_fine as f64 - 76800.0;
+        let var2 = calibration.dig_h4 as f64 * 64.0 + (calibration.dig_h5 as f64 / 16384.0) * var1;
+        let var3 = uncompensated as f64 - var2;
+        let var4 = calibration.dig_h2 as f64 / 65536.0;
+        let var5 = 1.0 + (calibration.dig_h3 as f64 / 67108864.0) * var1;
+        let var6 = 1.0 + (calibration.dig_h6 as f64 / 67108864.0) * var1 * var5;
+        let var6 = var3 * var4 * (var5 * var6);
+
+        let humidity = var6 * (1.0 - calibration.dig_h1 as f64 * var6 / 524288.0);
+        let humidity = humidity * f64::from(calibration.humidity_gain) / 65536.0
+            + f64::from(calibration.humidity_offset) / 100.0;
+        let humidity = humidity.clamp(
+            f64::from(BME280_HUMIDITY_MIN),
+            f64::from(BME280_HUMIDITY_MAX),
+        );
+        Ok(humidity)
+    }
+}
+
 #[cfg(feature = "fixed")]
 impl<E> Compensate<E, i32, fixed::types::U24F8, fixed::types::U22F10> for MeasurementsFixed<E> {
     fn make(
         temperature: i32,
-        pressure: fixed::types::U24F8,
-        humidity: fixed::types::U22F10,
+        pressure: Option<fixed::types::U24F8>,
+        humidity: Option<fixed::types::U22F10>,
     ) -> Self {
         MeasurementsFixed {
             temperature,
@@ -568,6 +2048,10 @@ impl<E> Compensate<E, i32, fixed::types::U24F8, fixed::types::U22F10> for Measur
         MeasurementsFixedRaw::compensate_temperature(uncompensated, calibration)
     }
 
+    fn temperature_from_t_fine(t_fine: i32) -> i32 {
+        <MeasurementsFixedRaw<E> as Compensate<E, i32, u32, u32>>::temperature_from_t_fine(t_fine)
+    }
+
     fn compensate_pressure(
         uncompensated: u32,
         calibration: &CalibrationData,
@@ -586,7 +2070,7 @@ impl<E> Compensate<E, i32, fixed::types::U24F8, fixed::types::U22F10> for Measur
 }
 
 impl<E> Compensate<E, i32, u32, u32> for MeasurementsFixedRaw<E> {
-    fn make(temperature: i32, pressure: u32, humidity: u32) -> Self {
+    fn make(temperature: i32, pressure: Option<u32>, humidity: Option<u32>) -> Self {
         MeasurementsFixedRaw {
             temperature,
             pressure,
@@ -609,13 +2093,17 @@ impl<E> Compensate<E, i32, u32, u32> for MeasurementsFixedRaw<E> {
             >> 12)
             * i32::from(calibration.dig_t3))
             >> 14;
-        calibration.t_fine = var1 + var2;
+        calibration.t_fine = var1 + var2 + calibration.t_fine_offset();
 
-        let temperature = (calibration.t_fine * 5 + 128) >> 8;
-        let temperature = temperature.clamp(BME280_TEMP_MIN_FIXED, BME280_TEMP_MAX_FIXED);
-        Ok(temperature)
+        Ok(Self::temperature_from_t_fine(calibration.t_fine))
     }
 
+    fn temperature_from_t_fine(t_fine: i32) -> i32 {
+        let temperature = (t_fine * 5 + 128) >> 8;
+        temperature.clamp(BME280_TEMP_MIN_FIXED, BME280_TEMP_MAX_FIXED)
+    }
+
+    #[cfg(not(feature = "pressure-32bit"))]
     fn compensate_pressure(
         uncompensated: u32,
         calibration: &CalibrationData,
@@ -639,8 +2127,41 @@ impl<E> Compensate<E, i32, u32, u32> for MeasurementsFixedRaw<E> {
         var1 = (i64::from(calibration.dig_p9) * (p >> 13) * (p >> 13)) >> 25;
         var2 = (i64::from(calibration.dig_p8) * p) >> 19;
         let p = ((p + var1 + var2) >> 8) + (i64::from(calibration.dig_p7) << 4);
-        let p = (p as u32).clamp(BME280_PRESSURE_MIN_FIXED, BME280_PRESSURE_MAX_FIXED);
-        Ok(p)
+        Ok(calibration.correct_pressure_fixed(p))
+    }
+
+    /// 32-bit only variant of the datasheet pressure formula. The result has a resolution
+    /// of 1 Pa and is widened to Q24.8 to keep the output format of the 64-bit path.
+    #[cfg(feature = "pressure-32bit")]
+    fn compensate_pressure(
+        uncompensated: u32,
+        calibration: &CalibrationData,
+    ) -> Result<u32, Error<E>> {
+        let uncompensated = uncompensated.cast_signed();
+
+        let mut var1 = (calibration.t_fine >> 1) - 64000;
+        let mut var2 = (((var1 >> 2) * (var1 >> 2)) >> 11) * i32::from(calibration.dig_p6);
+        var2 += (var1 * i32::from(calibration.dig_p5)) << 1;
+        var2 = (var2 >> 2) + (i32::from(calibration.dig_p4) << 16);
+        var1 = (((i32::from(calibration.dig_p3) * (((var1 >> 2) * (var1 >> 2)) >> 13)) >> 3)
+            + ((i32::from(calibration.dig_p2) * var1) >> 1))
+            >> 18;
+        var1 = ((32768 + var1) * i32::from(calibration.dig_p1)) >> 15;
+        if var1 == 0 {
+            return Err(Error::InvalidData);
+        }
+
+        let mut p = ((1048576 - uncompensated) - (var2 >> 12)).cast_unsigned() * 3125;
+        if p < 0x8000_0000 {
+            p = (p << 1) / var1.cast_unsigned();
+        } else {
+            p = (p / var1.cast_unsigned()) * 2;
+        }
+        var1 = (i32::from(calibration.dig_p9) * (((p >> 3) * (p >> 3)) >> 13).cast_signed()) >> 12;
+        var2 = ((p >> 2).cast_signed() * i32::from(calibration.dig_p8)) >> 13;
+        let p = (p.cast_signed() + ((var1 + var2 + i32::from(calibration.dig_p7)) >> 4))
+            .cast_unsigned();
+        Ok(calibration.correct_pressure_fixed(i64::from(p) << 8))
     }
 
     fn compensate_humidity(
@@ -665,13 +2186,51 @@ impl<E> Compensate<E, i32, u32, u32> for MeasurementsFixedRaw<E> {
 
         x1 -= ((((x1 >> 15) * (x1 >> 15)) >> 7) * i32::from(calibration.dig_h1)) >> 4;
         x1 >>= 12;
-        let h = x1
-            .cast_unsigned()
-            .clamp(BME280_HUMIDITY_MIN_FIXED, BME280_HUMIDITY_MAX_FIXED);
+        let h = calibration.correct_humidity_fixed(x1).cast_unsigned();
         Ok(h)
     }
 }
 
+/// Compensates raw ADC values with the given calibration data in floating point format
+#[cfg(feature = "float")]
+pub fn compensate<E>(
+    raw: &RawMeasurements,
+    calibration: &CalibrationData,
+) -> Result<Measurements<E>, Error<E>> {
+    let mut calibration = *calibration;
+    Measurements::compensate(raw, &mut calibration)
+}
+
+/// Compensates raw ADC values with the given calibration data in fixed-point format
+#[cfg(feature = "fixed")]
+pub fn compensate_fixed<E>(
+    raw: &RawMeasurements,
+    calibration: &CalibrationData,
+) -> Result<MeasurementsFixed<E>, Error<E>> {
+    let mut calibration = *calibration;
+    MeasurementsFixed::compensate(raw, &mut calibration)
+}
+
+/// Compensates raw ADC values with the given calibration data in double precision
+#[cfg(feature = "double-precision")]
+pub fn compensate_double<E>(
+    raw: &RawMeasurements,
+    calibration: &CalibrationData,
+) -> Result<MeasurementsDouble<E>, Error<E>> {
+    let mut calibration = *calibration;
+    MeasurementsDouble::compensate(raw, &mut calibration)
+}
+
+/// Compensates raw ADC values with the given calibration data in raw fixed-point format
+pub fn compensate_fixed_raw<E>(
+    raw: &RawMeasurements,
+    calibration: &CalibrationData,
+) -> Result<MeasurementsFixedRaw<E>, Error<E>> {
+    let mut calibration = *calibration;
+    MeasurementsFixedRaw::compensate(raw, &mut calibration)
+}
+
+#[cfg(feature = "sync")]
 trait Interface {
     type Error;
 
@@ -682,6 +2241,16 @@ trait Interface {
         register: u8,
     ) -> Result<[u8; BME280_P_T_H_DATA_LEN], Error<Self::Error>>;
 
+    fn read_pt_data(
+        &mut self,
+        register: u8,
+    ) -> Result<[u8; BME280_P_T_DATA_LEN], Error<Self::Error>>;
+
+    fn read_temperature_data(
+        &mut self,
+        register: u8,
+    ) -> Result<[u8; BME280_T_DATA_LEN], Error<Self::Error>>;
+
     fn read_pt_calib_data(
         &mut self,
         register: u8,
@@ -692,7 +2261,14 @@ trait Interface {
         register: u8,
     ) -> Result<[u8; BME280_H_CALIB_DATA_LEN], Error<Self::Error>>;
 
+    fn read_into(&mut self, register: u8, data: &mut [u8]) -> Result<(), Error<Self::Error>>;
+
     fn write_register(&mut self, register: u8, payload: u8) -> Result<(), Error<Self::Error>>;
+
+    /// Whether the sensor needs to be switched to 3-wire SPI
+    fn spi3w_en(&self) -> bool {
+        false
+    }
 }
 
 #[cfg(feature = "async")]
@@ -711,6 +2287,20 @@ trait AsyncInterface {
         Self: 'a;
     fn read_data(&mut self, register: u8) -> Self::ReadDataFuture<'_>;
 
+    type ReadPtDataFuture<'a>: Future<
+        Output = Result<[u8; BME280_P_T_DATA_LEN], Error<Self::Error>>,
+    >
+    where
+        Self: 'a;
+    fn read_pt_data(&mut self, register: u8) -> Self::ReadPtDataFuture<'_>;
+
+    type ReadTemperatureDataFuture<'a>: Future<
+        Output = Result<[u8; BME280_T_DATA_LEN], Error<Self::Error>>,
+    >
+    where
+        Self: 'a;
+    fn read_temperature_data(&mut self, register: u8) -> Self::ReadTemperatureDataFuture<'_>;
+
     type ReadPtCalibDataFuture<'a>: Future<
         Output = Result<[u8; BME280_P_T_CALIB_DATA_LEN], Error<Self::Error>>,
     >
@@ -725,10 +2315,20 @@ trait AsyncInterface {
         Self: 'a;
     fn read_h_calib_data(&mut self, register: u8) -> Self::ReadHCalibDataFuture<'_>;
 
+    type ReadIntoFuture<'a>: Future<Output = Result<(), Error<Self::Error>>>
+    where
+        Self: 'a;
+    fn read_into<'a>(&'a mut self, register: u8, data: &'a mut [u8]) -> Self::ReadIntoFuture<'a>;
+
     type WriteRegisterFuture<'a>: Future<Output = Result<(), Error<Self::Error>>>
     where
         Self: 'a;
     fn write_register(&mut self, register: u8, payload: u8) -> Self::WriteRegisterFuture<'_>;
+
+    /// Whether the sensor needs to be switched to 3-wire SPI
+    fn spi3w_en(&self) -> bool {
+        false
+    }
 }
 
 /// Common driver code for I2C and SPI interfaces
@@ -742,6 +2342,77 @@ struct AsyncBME280Common<I> {
     interface: I,
     /// calibration data
     calibration: Option<CalibrationData>,
+    /// sensor variant detected during initialization
+    chip_model: Option<ChipModel>,
+    /// configuration applied to the sensor
+    config: Configuration,
+    /// cached configuration register contents, used to skip redundant writes
+    shadow: Option<ShadowRegisters>,
+    /// whether register writes are read back and compared
+    verify_writes: bool,
+    /// whether measurements are checked for plausibility before compensation
+    validate: bool,
+    /// whether the calibration data is read on the first conversion instead of during
+    /// initialization
+    lazy_calibration: bool,
+    /// whether normal mode was active when the sensor was put to sleep
+    resume_normal: bool,
+    /// whether the driver last put the sensor into normal mode
+    normal_mode: bool,
+    /// number of consecutive failed forced conversions after which the sensor is recovered, 0
+    /// to never recover automatically
+    recovery_threshold: u8,
+    /// number of consecutive forced conversions that failed with a bus error or a timeout
+    consecutive_failures: u8,
+    /// fine resolution temperature of the last compensated measurement
+    t_fine: Option<i32>,
+    /// age in microseconds up to which pressure measurements may reuse `t_fine` instead of
+    /// converting the temperature, 0 to never reuse it
+    t_fine_max_age_us: u32,
+    /// caller timestamp in microseconds of the pressure measurement that converted the
+    /// temperature of `t_fine`, `None` if it was converted by another measurement
+    t_fine_converted_us: Option<u64>,
+    /// data registers of the last frame returned by a coalesced status and data read
+    last_frame: Option<[u8; BME280_P_T_H_DATA_LEN]>,
+    /// whether a conversion was seen running since `last_frame` was returned
+    conversion_seen: bool,
+    /// whether a coalesced read also reports changed data registers as a new frame when no
+    /// conversion was seen running
+    compare_frames: bool,
+    /// sequence number of the last compensated measurement
+    sequence: u32,
+    metrics: MetricsCounter,
+}
+
+#[maybe_async_cfg::maybe(
+    sync(feature = "sync", self = "BME280Common"),
+    async(feature = "async", keep_self)
+)]
+impl<I> AsyncBME280Common<I> {
+    fn new(interface: I) -> Self {
+        Self {
+            interface,
+            calibration: None,
+            chip_model: None,
+            config: Configuration::default(),
+            shadow: None,
+            verify_writes: false,
+            validate: false,
+            lazy_calibration: false,
+            resume_normal: false,
+            normal_mode: false,
+            recovery_threshold: 0,
+            consecutive_failures: 0,
+            t_fine: None,
+            t_fine_max_age_us: 0,
+            t_fine_converted_us: None,
+            last_frame: None,
+            conversion_seen: false,
+            compare_frames: false,
+            sequence: 0,
+            metrics: MetricsCounter::default(),
+        }
+    }
 }
 
 #[maybe_async_cfg::maybe(
@@ -762,173 +2433,897 @@ where
         delay: &mut D,
         config: Configuration,
     ) -> Result<(), Error<I::Error>> {
+        self.enable_spi3w().await?;
+        self.verify_chip_id().await?;
+        self.soft_reset(delay).await?;
+        if !self.lazy_calibration {
+            self.calibrate().await?;
+        }
+        self.configure(delay, config).await
+    }
+
+    /// Initializes the BME280 with previously read calibration data, applying the given config.
+    async fn init_with_calibration<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+        config: Configuration,
+        calibration: CalibrationData,
+    ) -> Result<(), Error<I::Error>> {
+        self.enable_spi3w().await?;
         self.verify_chip_id().await?;
         self.soft_reset(delay).await?;
-        self.calibrate().await?;
+        self.calibration = Some(calibration);
+        self.t_fine = None;
         self.configure(delay, config).await
     }
 
     async fn verify_chip_id(&mut self) -> Result<(), Error<I::Error>> {
-        let chip_id = self.interface.read_register(BME280_CHIP_ID_ADDR).await?;
-        if chip_id == BME280_CHIP_ID || chip_id == BMP280_CHIP_ID {
-            Ok(())
-        } else {
-            Err(Error::UnsupportedChip)
-        }
+        self.chip_model = Some(self.chip_model().await?);
+        Ok(())
+    }
+
+    fn calibration_mut(&mut self) -> Result<&mut CalibrationData, Error<I::Error>> {
+        self.calibration.as_mut().ok_or(Error::NoCalibrationData)
+    }
+
+    fn has_humidity(&self) -> bool {
+        self.chip_model.is_some_and(|model| model.has_humidity())
+    }
+
+    async fn chip_model(&mut self) -> Result<ChipModel, Error<I::Error>> {
+        let chip_id = self
+            .metrics
+            .record(self.interface.read_register(BME280_CHIP_ID_ADDR).await)?;
+        ChipModel::from_id(chip_id).ok_or(Error::UnsupportedChip)
     }
 
     async fn soft_reset<D: AsyncDelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
-        self.interface
-            .write_register(BME280_RESET_ADDR, BME280_SOFT_RESET_CMD)
+        self.write_register(BME280_RESET_ADDR, BME280_SOFT_RESET_CMD)
             .await?;
         delay.delay_ms(2).await; // startup time is 2ms
-        Ok(())
+        self.shadow = None;
+        // The sensor is back in sleep mode.
+        self.normal_mode = false;
+        self.resume_normal = false;
+        self.forget_frame();
+        self.enable_spi3w().await
+    }
+
+    /// Switches the sensor to 3-wire SPI if the interface requires it.
+    /// The sensor starts in 4-wire mode, but register writes work the same in both modes.
+    async fn enable_spi3w(&mut self) -> Result<(), Error<I::Error>> {
+        if self.interface.spi3w_en() {
+            self.write_register(BME280_CONFIG_ADDR, BME280_SPI3W_EN_MSK)
+                .await
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Resets the sensor and applies the given config, keeping the calibration data
+    async fn reset<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+        config: Configuration,
+    ) -> Result<(), Error<I::Error>> {
+        self.soft_reset(delay).await?;
+        self.configure(delay, config).await
     }
 
     async fn calibrate(&mut self) -> Result<(), Error<I::Error>> {
-        let pt_calib_data = self
-            .interface
-            .read_pt_calib_data(BME280_P_T_CALIB_DATA_ADDR)
-            .await?;
-        let h_calib_data = self
-            .interface
-            .read_h_calib_data(BME280_H_CALIB_DATA_ADDR)
-            .await?;
-        self.calibration = Some(parse_calib_data(&pt_calib_data, &h_calib_data));
+        let mut calibration = self.read_calibration().await?;
+        if let Some(previous) = &self.calibration {
+            calibration.copy_user_offsets(previous);
+        }
+        self.calibration = Some(calibration);
+        self.t_fine = None;
         Ok(())
     }
 
+    /// Reads the calibration data from the sensor without storing it.
+    /// Each of the two calibration banks is read in a single burst, i.e. one I²C transaction or
+    /// one SPI transaction, unless a maximum transfer size splits it. The humidity bank is
+    /// skipped on sensors known to have no humidity channel.
+    async fn read_calibration(&mut self) -> Result<CalibrationData, Error<I::Error>> {
+        let pt_calib_data = self.metrics.record(
+            self.interface
+                .read_pt_calib_data(BME280_P_T_CALIB_DATA_ADDR)
+                .await,
+        )?;
+        let h_calib_data = match self.chip_model {
+            Some(model) if !model.has_humidity() => [0; BME280_H_CALIB_DATA_LEN],
+            _ => self.metrics.record(
+                self.interface
+                    .read_h_calib_data(BME280_H_CALIB_DATA_ADDR)
+                    .await,
+            )?,
+        };
+        Ok(parse_calib_data(&pt_calib_data, &h_calib_data))
+    }
+
     async fn configure<D: AsyncDelayNs>(
         &mut self,
         delay: &mut D,
         config: Configuration,
     ) -> Result<(), Error<I::Error>> {
+        let registers = ShadowRegisters::new(&config, self.interface.spi3w_en());
+        if self.shadow == Some(registers) {
+            self.config = config;
+            return Ok(());
+        }
+
         match self.mode().await? {
             SensorMode::Sleep => {}
             _ => self.soft_reset(delay).await?,
         };
 
-        self.interface
-            .write_register(
-                BME280_CTRL_HUM_ADDR,
-                config.humidity_oversampling.bits() & BME280_CTRL_HUM_MSK,
-            )
-            .await?;
+        let shadow = self.shadow.take();
+        let ctrl_hum_changed = shadow.is_none_or(|shadow| shadow.ctrl_hum != registers.ctrl_hum);
+        if ctrl_hum_changed {
+            self.write_register(BME280_CTRL_HUM_ADDR, registers.ctrl_hum)
+                .await?;
+        }
 
         // As per the datasheet, the ctrl_meas register needs to be written after
         // the ctrl_hum register for changes to take effect.
-        let data = self.interface.read_register(BME280_CTRL_MEAS_ADDR).await?;
-        let data = set_bits!(
-            data,
-            BME280_CTRL_PRESS_MSK,
-            BME280_CTRL_PRESS_POS,
-            config.pressure_oversampling.bits()
-        );
-        let data = set_bits!(
-            data,
-            BME280_CTRL_TEMP_MSK,
-            BME280_CTRL_TEMP_POS,
-            config.temperature_oversampling.bits()
-        );
-        self.interface
-            .write_register(BME280_CTRL_MEAS_ADDR, data)
+        if ctrl_hum_changed || shadow.is_none_or(|shadow| shadow.ctrl_meas != registers.ctrl_meas) {
+            self.write_register(BME280_CTRL_MEAS_ADDR, registers.ctrl_meas)
+                .await?;
+        }
+
+        if shadow.is_none_or(|shadow| shadow.config != registers.config) {
+            self.write_register(BME280_CONFIG_ADDR, registers.config)
+                .await?;
+        }
+        self.shadow = Some(registers);
+        self.config = config;
+        self.forget_frame();
+        Ok(())
+    }
+
+    /// Changes the humidity oversampling without resetting the sensor or leaving the current mode
+    async fn set_humidity_oversampling(
+        &mut self,
+        oversampling: Oversampling,
+    ) -> Result<(), Error<I::Error>> {
+        let config = self.config.with_humidity_oversampling(oversampling);
+        let registers = ShadowRegisters::new(&config, self.interface.spi3w_en());
+        self.write_register(BME280_CTRL_HUM_ADDR, registers.ctrl_hum)
+            .await?;
+
+        // As per the datasheet, changes to the ctrl_hum register only become effective after a
+        // write to the ctrl_meas register. Its current contents are written back to keep the mode.
+        let ctrl_meas = self
+            .metrics
+            .record(self.interface.read_register(BME280_CTRL_MEAS_ADDR).await)?;
+        self.write_register(BME280_CTRL_MEAS_ADDR, ctrl_meas)
             .await?;
 
-        let data = self.interface.read_register(BME280_CONFIG_ADDR).await?;
-        let data = set_bits!(
-            data,
-            BME280_FILTER_MSK,
-            BME280_FILTER_POS,
-            config.iir_filter.bits()
+        self.shadow = self.shadow.map(|shadow| ShadowRegisters {
+            ctrl_hum: registers.ctrl_hum,
+            ..shadow
+        });
+        self.config = config;
+        Ok(())
+    }
+
+    /// Reads the configuration registers and decodes them
+    async fn configuration(&mut self) -> Result<Configuration, Error<I::Error>> {
+        let ctrl_hum = self
+            .metrics
+            .record(self.interface.read_register(BME280_CTRL_HUM_ADDR).await)?;
+        let ctrl_meas = self
+            .metrics
+            .record(self.interface.read_register(BME280_CTRL_MEAS_ADDR).await)?;
+        let config = self
+            .metrics
+            .record(self.interface.read_register(BME280_CONFIG_ADDR).await)?;
+        let (ctrl_hum, ctrl_meas, config) = (
+            registers::CtrlHum::from_bits(ctrl_hum),
+            registers::CtrlMeas::from_bits(ctrl_meas),
+            registers::Config::from_bits(config),
         );
-        self.interface
-            .write_register(BME280_CONFIG_ADDR, data)
-            .await
+
+        Ok(Configuration {
+            temperature_oversampling: ctrl_meas.temperature_oversampling(),
+            pressure_oversampling: ctrl_meas.pressure_oversampling(),
+            humidity_oversampling: ctrl_hum.humidity_oversampling(),
+            iir_filter: config.iir_filter(),
+            standby_time: config.standby_time(),
+        })
     }
 
     async fn mode(&mut self) -> Result<SensorMode, Error<I::Error>> {
-        let data = self.interface.read_register(BME280_PWR_CTRL_ADDR).await?;
-        match data & BME280_SENSOR_MODE_MSK {
-            BME280_SLEEP_MODE => Ok(SensorMode::Sleep),
-            BME280_FORCED_MODE => Ok(SensorMode::Forced),
-            BME280_NORMAL_MODE => Ok(SensorMode::Normal),
-            _ => Err(Error::InvalidData),
-        }
+        let data = self
+            .metrics
+            .record(self.interface.read_register(BME280_PWR_CTRL_ADDR).await)?;
+        Ok(registers::CtrlMeas::from_bits(data).mode())
+    }
+
+    async fn forced(&mut self) -> Result<(), Error<I::Error>> {
+        self.set_mode(BME280_FORCED_MODE).await
+    }
+
+    /// Puts the sensor into normal mode, where conversions run continuously
+    async fn normal(&mut self) -> Result<(), Error<I::Error>> {
+        self.set_mode(BME280_NORMAL_MODE).await
+    }
+
+    async fn status(&mut self) -> Result<Status, Error<I::Error>> {
+        let data = self
+            .metrics
+            .record(self.interface.read_register(BME280_STATUS_ADDR).await)?;
+        Ok(registers::Status::from_bits(data).into())
     }
 
-    async fn forced<D: AsyncDelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I::Error>> {
-        self.set_mode(BME280_FORCED_MODE, delay).await
+    /// Writes a register, reading it back afterwards if write verification is enabled
+    async fn write_register(&mut self, register: u8, payload: u8) -> Result<(), Error<I::Error>> {
+        debug_log!("bme280: write {:#x} to register {:#x}", payload, register);
+        self.metrics
+            .record(self.interface.write_register(register, payload).await)?;
+        if !self.verify_writes {
+            return Ok(());
+        }
+
+        let mask = match register {
+            // The reset register always reads back as zero.
+            BME280_RESET_ADDR => return Ok(()),
+            BME280_CTRL_HUM_ADDR => BME280_CTRL_HUM_MSK,
+            // The sensor leaves forced mode on its own once the conversion is done.
+            BME280_CTRL_MEAS_ADDR if payload & BME280_SENSOR_MODE_MSK == BME280_FORCED_MODE => {
+                !BME280_SENSOR_MODE_MSK
+            }
+            BME280_CONFIG_ADDR => BME280_CONFIG_MSK,
+            _ => 0xFF,
+        };
+        let data = self
+            .metrics
+            .record(self.interface.read_register(register).await)?;
+        if data & mask == payload & mask {
+            Ok(())
+        } else {
+            Err(Error::WriteVerificationFailed)
+        }
     }
 
-    async fn set_mode<D: AsyncDelayNs>(
+    /// Waits until the running conversion is done, polling with an increasing backoff.
+    /// In forced mode, the mode bits are polled until the sensor is back in sleep mode, since the
+    /// measuring bit may not be raised yet right after the conversion was triggered. In normal
+    /// mode, the next conversion is waited for, so the timeout is extended to one cycle.
+    async fn wait_for_data_ready<D: AsyncDelayNs>(
         &mut self,
-        mode: u8,
         delay: &mut D,
     ) -> Result<(), Error<I::Error>> {
+        let mut waited_us = 0;
+        let mut backoff_us = BME280_POLL_INITIAL_US;
         match self.mode().await? {
             SensorMode::Sleep => {}
-            _ => self.soft_reset(delay).await?,
-        };
-        let data = self.interface.read_register(BME280_PWR_CTRL_ADDR).await?;
-        let data = set_bits!(data, BME280_SENSOR_MODE_MSK, 0, mode);
-        self.interface
-            .write_register(BME280_PWR_CTRL_ADDR, data)
-            .await
+            SensorMode::Forced => {
+                while self.mode().await? == SensorMode::Forced {
+                    Self::backoff(
+                        delay,
+                        &mut waited_us,
+                        &mut backoff_us,
+                        BME280_POLL_TIMEOUT_US,
+                    )
+                    .await?;
+                }
+            }
+            SensorMode::Normal => {
+                let timeout_us = BME280_POLL_TIMEOUT_US.max(self.config.cycle_time_us());
+                // Polled without backoff, so a short conversion is not missed
+                while !self.status().await?.measuring {
+                    let mut interval_us = BME280_POLL_INITIAL_US;
+                    Self::backoff(delay, &mut waited_us, &mut interval_us, timeout_us).await?;
+                }
+                while self.status().await?.measuring {
+                    Self::backoff(delay, &mut waited_us, &mut backoff_us, timeout_us).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Waits `backoff_us` and doubles it, failing once `waited_us` reaches the timeout
+    async fn backoff<D: AsyncDelayNs>(
+        delay: &mut D,
+        waited_us: &mut u32,
+        backoff_us: &mut u32,
+        timeout_us: u32,
+    ) -> Result<(), Error<I::Error>> {
+        if *waited_us >= timeout_us {
+            return Err(Error::Timeout);
+        }
+        delay.delay_us(*backoff_us).await;
+        *waited_us += *backoff_us;
+        *backoff_us = (*backoff_us * 2).min(BME280_POLL_MAX_US);
+        Ok(())
     }
 
-    /// Captures and processes sensor data for temperature, pressure, and humidity
-    async fn measure<D: AsyncDelayNs>(
+    /// Soft resets the sensor and restores the applied configuration and normal mode, reusing
+    /// the calibration data unless none was read yet.
+    /// A sensor put to sleep by `sleep()` stays asleep, and `wake()` still resumes normal mode.
+    async fn recover<D: AsyncDelayNs>(
         &mut self,
         delay: &mut D,
-    ) -> Result<Measurements<I::Error>, Error<I::Error>> {
-        self.forced(delay).await?;
-        delay.delay_ms(40).await;
-        let measurements = self.interface.read_data(BME280_DATA_ADDR).await?;
-        match self.calibration.as_mut() {
-            Some(calibration) => {
-                let measurements = Measurements::parse(measurements, &mut *calibration)?;
-                Ok(measurements)
-            }
-            None => Err(Error::NoCalibrationData),
+    ) -> Result<RecoveryReport, Error<I::Error>> {
+        let (normal_mode, resume_normal) = (self.normal_mode, self.resume_normal);
+        self.consecutive_failures = 0;
+        self.soft_reset(delay).await?;
+        let chip_model = self.chip_model().await?;
+        self.chip_model = Some(chip_model);
+        let calibration_read = self.calibration.is_none() && !self.lazy_calibration;
+        if calibration_read {
+            self.calibrate().await?;
+        }
+        let config = self.config;
+        self.configure(delay, config).await?;
+        if normal_mode {
+            self.normal().await?;
+        }
+        self.resume_normal = resume_normal;
+        Ok(RecoveryReport {
+            chip_model,
+            calibration_read,
+            normal_mode_resumed: normal_mode,
+        })
+    }
+
+    /// Puts the sensor into sleep mode, keeping track of whether normal mode should be resumed
+    async fn sleep(&mut self) -> Result<(), Error<I::Error>> {
+        if let SensorMode::Normal = self.mode().await? {
+            self.resume_normal = true;
+        }
+        self.set_mode(BME280_SLEEP_MODE).await
+    }
+
+    /// Wakes the sensor, resuming normal mode if it was active before going to sleep
+    async fn wake(&mut self) -> Result<(), Error<I::Error>> {
+        if core::mem::take(&mut self.resume_normal) {
+            self.normal().await
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn set_mode(&mut self, mode: u8) -> Result<(), Error<I::Error>> {
+        let registers = ShadowRegisters::new(&self.config, self.interface.spi3w_en());
+        self.set_mode_with(mode, registers).await
+    }
+
+    /// Changes the mode, writing the oversampling settings of the given registers.
+    /// This restores the configured oversampling after a measurement that skipped channels.
+    async fn set_mode_with(
+        &mut self,
+        mode: u8,
+        registers: ShadowRegisters,
+    ) -> Result<(), Error<I::Error>> {
+        // forced mode triggers a conversion
+        debug_log!("bme280: switch to mode {:#x}", mode);
+        self.normal_mode = mode == BME280_NORMAL_MODE;
+        self.forget_frame();
+        let data = self
+            .metrics
+            .record(self.interface.read_register(BME280_PWR_CTRL_ADDR).await)?;
+        // Mode changes other than from sleep mode must go through sleep mode first.
+        if data & BME280_SENSOR_MODE_MSK != BME280_SLEEP_MODE {
+            let data = set_bits!(data, BME280_SENSOR_MODE_MSK, 0, BME280_SLEEP_MODE);
+            self.write_register(BME280_PWR_CTRL_ADDR, data).await?;
+        }
+        if mode == BME280_SLEEP_MODE {
+            return Ok(());
+        }
+        if self.lazy_calibration && self.calibration.is_none() {
+            self.calibrate().await?;
+        }
+
+        if self
+            .shadow
+            .is_none_or(|shadow| shadow.ctrl_hum != registers.ctrl_hum)
+        {
+            self.write_register(BME280_CTRL_HUM_ADDR, registers.ctrl_hum)
+                .await?;
+        }
+        // This also makes a ctrl_hum change effective.
+        let data = set_bits!(registers.ctrl_meas, BME280_SENSOR_MODE_MSK, 0, mode);
+        self.write_register(BME280_CTRL_MEAS_ADDR, data).await?;
+
+        self.shadow = self.shadow.map(|shadow| ShadowRegisters {
+            ctrl_hum: registers.ctrl_hum,
+            ctrl_meas: registers.ctrl_meas,
+            ..shadow
+        });
+        Ok(())
+    }
+
+    /// Captures and processes sensor data for temperature, pressure, and humidity in the
+    /// given format
+    async fn measure_as<M: MeasurementFormat<I::Error>, D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<M, Error<I::Error>> {
+        let config = self.config;
+        let raw = self.convert(delay, config).await?;
+        self.compensate(&raw, &config, None)
+    }
+
+    /// Triggers a forced conversion of the channels of `config` and reads their data registers.
+    /// Once the recovery threshold is reached, the sensor is recovered and the conversion
+    /// repeated once.
+    async fn convert<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+        config: Configuration,
+    ) -> Result<RawMeasurements, Error<I::Error>> {
+        let result = self.convert_once(delay, &config).await;
+        if !self.count_failure(&result) {
+            return result;
         }
+        self.recover(delay).await?;
+        let result = self.convert_once(delay, &config).await;
+        self.count_failure(&result);
+        result
     }
 
-    /// Captures and processes sensor data for temperature, pressure, and humidity in fixed-point format
-    #[cfg(feature = "fixed")]
-    async fn measure_fixed<D: AsyncDelayNs>(
+    /// Triggers a single forced conversion of the channels of `config` and reads only the data
+    /// registers of the converted channels
+    async fn convert_once<D: AsyncDelayNs>(
         &mut self,
         delay: &mut D,
-    ) -> Result<MeasurementsFixed<I::Error>, Error<I::Error>> {
-        self.forced(delay).await?;
-        delay.delay_ms(40).await;
-        let measurements = self.interface.read_data(BME280_DATA_ADDR).await?;
-        match self.calibration.as_mut() {
-            Some(calibration) => {
-                let measurements = MeasurementsFixed::parse(measurements, &mut *calibration)?;
-                Ok(measurements)
+        config: &Configuration,
+    ) -> Result<RawMeasurements, Error<I::Error>> {
+        let registers = ShadowRegisters::new(config, self.interface.spi3w_en());
+        self.set_mode_with(BME280_FORCED_MODE, registers).await?;
+        delay.delay_us(config.max_measurement_time_us()).await;
+
+        let humidity_skipped =
+            config.humidity_oversampling == Oversampling::Skipped || !self.has_humidity();
+        if humidity_skipped && config.pressure_oversampling == Oversampling::Skipped {
+            let data = self.metrics.record(
+                self.interface
+                    .read_temperature_data(BME280_TEMPERATURE_DATA_ADDR)
+                    .await,
+            )?;
+            Ok(RawMeasurements::from_temperature_data(data))
+        } else if humidity_skipped {
+            let data = self
+                .metrics
+                .record(self.interface.read_pt_data(BME280_DATA_ADDR).await)?;
+            Ok(RawMeasurements::from_pt_data(data))
+        } else {
+            self.read_measurement_raw().await
+        }
+    }
+
+    /// Counts consecutive forced conversions failing with a bus error or a timeout, and returns
+    /// whether the recovery threshold is reached
+    fn count_failure<T>(&mut self, result: &Result<T, Error<I::Error>>) -> bool {
+        match result {
+            Err(Error::Bus(_) | Error::Timeout) => {
+                self.consecutive_failures = self.consecutive_failures.saturating_add(1);
             }
-            None => Err(Error::NoCalibrationData),
+            _ => self.consecutive_failures = 0,
+        }
+        self.recovery_threshold != 0 && self.consecutive_failures >= self.recovery_threshold
+    }
+
+    /// Captures the given number of conversions back to back and processes the mean of the raw
+    /// values in the given format
+    async fn measure_averaged_as<M: MeasurementFormat<I::Error>, D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+        samples: NonZeroU16,
+    ) -> Result<M, Error<I::Error>> {
+        let (mut temperature, mut pressure, mut humidity) = (0u64, 0u64, 0u64);
+        for _ in 0..samples.get() {
+            let raw = self.measure_raw(delay).await?;
+            temperature += u64::from(raw.temperature);
+            pressure += u64::from(raw.pressure);
+            humidity += u64::from(raw.humidity);
+        }
+
+        // raw values are at most 20 bits wide, so the rounded mean always fits in a u32
+        let count = u64::from(samples.get());
+        let mean = |sum: u64| ((sum + count / 2) / count) as u32;
+        let raw = RawMeasurements {
+            temperature: mean(temperature),
+            pressure: mean(pressure),
+            humidity: mean(humidity),
+        };
+        let config = self.config;
+        self.compensate(&raw, &config, None)
+    }
+
+    /// Captures `N` conversions back to back and processes the per-channel median of the raw
+    /// values in the given format. `N` must be odd.
+    async fn measure_median_as<const N: usize, M: MeasurementFormat<I::Error>, D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<M, Error<I::Error>> {
+        const { assert!(N % 2 == 1, "the number of samples must be odd") };
+
+        let (mut temperature, mut pressure, mut humidity) = ([0; N], [0; N], [0; N]);
+        for i in 0..N {
+            let raw = self.measure_raw(delay).await?;
+            temperature[i] = raw.temperature;
+            pressure[i] = raw.pressure;
+            humidity[i] = raw.humidity;
         }
+
+        let median = |mut values: [u32; N]| {
+            values.sort_unstable();
+            values[N / 2]
+        };
+        let raw = RawMeasurements {
+            temperature: median(temperature),
+            pressure: median(pressure),
+            humidity: median(humidity),
+        };
+        let config = self.config;
+        self.compensate(&raw, &config, None)
+    }
+
+    /// Reads and processes the most recent sensor data in the given format without triggering
+    /// a conversion
+    async fn read_measurement_as<M: MeasurementFormat<I::Error>>(
+        &mut self,
+    ) -> Result<M, Error<I::Error>> {
+        let raw = self.read_measurement_raw().await?;
+        let config = self.config;
+        self.compensate(&raw, &config, None)
+    }
+
+    /// Captures and processes only the temperature in the given format, skipping the pressure
+    /// and humidity conversions and reading only the temperature data registers
+    async fn measure_temperature_as<M: MeasurementFormat<I::Error>, D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<M, Error<I::Error>> {
+        let config = self
+            .config
+            .with_pressure_oversampling(Oversampling::Skipped)
+            .with_humidity_oversampling(Oversampling::Skipped);
+        let raw = self.convert(delay, config).await?;
+        self.compensate(&raw, &config, None)
     }
 
-    /// Captures and processes sensor data for temperature, pressure, and humidity in raw fixed-point format
-    async fn measure_fixed_raw<D: AsyncDelayNs>(
+    /// Captures and processes pressure and temperature in the given format, skipping the
+    /// humidity conversion and reading only the pressure and temperature data registers.
+    /// `now_us` is the caller timestamp that ages `t_fine` for its reuse.
+    async fn measure_pt_as<M: MeasurementFormat<I::Error>, D: AsyncDelayNs>(
         &mut self,
         delay: &mut D,
-    ) -> Result<MeasurementsFixedRaw<I::Error>, Error<I::Error>> {
-        self.forced(delay).await?;
-        delay.delay_ms(40).await;
-        let measurements = self.interface.read_data(BME280_DATA_ADDR).await?;
-        match self.calibration.as_mut() {
-            Some(calibration) => {
-                let measurements = MeasurementsFixedRaw::parse(measurements, &mut *calibration)?;
-                Ok(measurements)
+        now_us: u64,
+    ) -> Result<M, Error<I::Error>> {
+        let max_age_us = u64::from(self.t_fine_max_age_us);
+        let t_fine = self
+            .t_fine
+            .zip(self.t_fine_converted_us)
+            .filter(|&(_, converted_us)| {
+                max_age_us > 0
+                    && now_us
+                        .checked_sub(converted_us)
+                        .is_some_and(|age_us| age_us <= max_age_us)
+            })
+            .map(|(t_fine, _)| t_fine);
+        let mut config = self
+            .config
+            .with_humidity_oversampling(Oversampling::Skipped);
+        if t_fine.is_some() {
+            config = config.with_temperature_oversampling(Oversampling::Skipped);
+        }
+        let raw = self.convert(delay, config).await?;
+        let measurements = self.compensate(&raw, &config, t_fine)?;
+        if t_fine.is_none() {
+            self.t_fine_converted_us = Some(now_us);
+        }
+        Ok(measurements)
+    }
+
+    /// Compensates raw ADC values with the calibration data, keeping track of `t_fine`.
+    /// `config` gives the channels that were converted, for validation.
+    /// If `reused_t_fine` is given, it replaces the raw temperature, which is then not checked.
+    fn compensate<M: MeasurementFormat<I::Error>>(
+        &mut self,
+        raw: &RawMeasurements,
+        config: &Configuration,
+        reused_t_fine: Option<i32>,
+    ) -> Result<M, Error<I::Error>> {
+        debug_log!(
+            "bme280: compensate raw temperature {}, pressure {}, humidity {}",
+            raw.temperature,
+            raw.pressure,
+            raw.humidity
+        );
+        if self.validate
+            && let Err(error) = self.validate(raw, config, reused_t_fine)
+        {
+            self.metrics.record_validation_failure();
+            return Err(error);
+        }
+        let Some(calibration) = self.calibration.as_mut() else {
+            return Err(Error::NoCalibrationData);
+        };
+        let measurements = match reused_t_fine {
+            Some(t_fine) => M::compensate_with_t_fine(raw, &mut *calibration, t_fine)?,
+            None => {
+                self.t_fine_converted_us = None;
+                M::compensate(raw, &mut *calibration)?
             }
-            None => Err(Error::NoCalibrationData),
+        };
+        self.t_fine = Some(calibration.t_fine);
+        self.sequence = self.sequence.wrapping_add(1);
+        Ok(measurements)
+    }
+
+    /// Rejects raw measurements holding the power-on reset value in a converted channel, and
+    /// measurements where the compensation saturated at the temperature or pressure limits
+    fn validate(
+        &self,
+        raw: &RawMeasurements,
+        config: &Configuration,
+        reused_t_fine: Option<i32>,
+    ) -> Result<(), Error<I::Error>> {
+        let Some(mut calibration) = self.calibration else {
+            return Err(Error::NoCalibrationData);
+        };
+
+        let reset_values = (reused_t_fine.is_none()
+            && raw.temperature == BME280_SKIPPED_TEMPERATURE)
+            || (config.pressure_oversampling != Oversampling::Skipped
+                && raw.pressure == BME280_SKIPPED_PRESSURE)
+            || (self.has_humidity()
+                && config.humidity_oversampling != Oversampling::Skipped
+                && raw.humidity == BME280_SKIPPED_HUMIDITY);
+        if reset_values {
+            return Err(Error::ImplausibleMeasurement);
+        }
+
+        let measurements: MeasurementsFixedRaw<I::Error> = match reused_t_fine {
+            Some(t_fine) => Compensate::compensate_with_t_fine(raw, &mut calibration, t_fine)?,
+            None => Compensate::compensate(raw, &mut calibration)?,
+        };
+        if measurements.is_plausible() {
+            Ok(())
+        } else {
+            Err(Error::ImplausibleMeasurement)
+        }
+    }
+
+    /// Captures sensor data for temperature, pressure, and humidity without compensation
+    async fn measure_raw<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<RawMeasurements, Error<I::Error>> {
+        let config = self.config;
+        self.convert(delay, config).await
+    }
+
+    /// Reads the most recent sensor data without compensation or triggering a conversion
+    async fn read_measurement_raw(&mut self) -> Result<RawMeasurements, Error<I::Error>> {
+        let measurements = self
+            .metrics
+            .record(self.interface.read_data(BME280_DATA_ADDR).await)?;
+        Ok(RawMeasurements::from_data(
+            measurements,
+            self.has_humidity(),
+        ))
+    }
+
+    /// Reads the status and data registers in one burst and processes the data in the given
+    /// format, or returns `None` while a conversion is running or if no new frame is available.
+    /// A frame is new once a conversion was seen running since the last returned frame, or, if
+    /// frame comparison is enabled, once the data registers changed.
+    async fn poll_measurement_as<M: MeasurementFormat<I::Error>>(
+        &mut self,
+    ) -> Result<Option<M>, Error<I::Error>> {
+        let mut burst = [0; BME280_STATUS_DATA_LEN];
+        self.metrics.record(
+            self.interface
+                .read_into(registers::STATUS, &mut burst)
+                .await,
+        )?;
+        if registers::Status::from_bits(burst[0]).measuring() {
+            self.conversion_seen = true;
+            return Ok(None);
+        }
+        let mut frame = [0; BME280_P_T_H_DATA_LEN];
+        frame.copy_from_slice(&burst[BME280_STATUS_DATA_LEN - BME280_P_T_H_DATA_LEN..]);
+        let new = self.last_frame.is_none_or(|last_frame| {
+            self.conversion_seen || (self.compare_frames && last_frame != frame)
+        });
+        if !new {
+            return Ok(None);
+        }
+
+        let raw = RawMeasurements::from_data(frame, self.has_humidity());
+        let config = self.config;
+        let measurements = self.compensate(&raw, &config, None)?;
+        self.last_frame = Some(frame);
+        self.conversion_seen = false;
+        Ok(Some(measurements))
+    }
+
+    /// Forgets the last frame returned by a coalesced read, after a change of the configuration
+    /// or the mode
+    fn forget_frame(&mut self) {
+        self.last_frame = None;
+        self.conversion_seen = false;
+    }
+
+    /// Reads the data registers into the first [`registers::DATA_LEN`] bytes of `data`
+    async fn read_data_into(&mut self, data: &mut [u8]) -> Result<(), Error<I::Error>> {
+        let data = data
+            .get_mut(..BME280_P_T_H_DATA_LEN)
+            .ok_or(Error::InvalidData)?;
+        self.metrics
+            .record(self.interface.read_into(BME280_DATA_ADDR, data).await)
+    }
+
+    /// Processes the data registers held in the first [`registers::DATA_LEN`] bytes of `data`
+    /// in the given format
+    fn process_data_as<M: MeasurementFormat<I::Error>>(
+        &mut self,
+        data: &[u8],
+    ) -> Result<M, Error<I::Error>> {
+        let data = data
+            .get(..BME280_P_T_H_DATA_LEN)
+            .and_then(|data| data.try_into().ok())
+            .ok_or(Error::InvalidData)?;
+        let raw = RawMeasurements::from_data(data, self.has_humidity());
+        let config = self.config;
+        self.compensate(&raw, &config, None)
+    }
+}
+
+impl CalibrationData {
+    /// Parses the calibration registers, i.e. the 26 bytes starting at 0x88 and the 7 bytes
+    /// starting at 0xE1.
+    pub fn from_registers(
+        pt_data: &[u8; BME280_P_T_CALIB_DATA_LEN],
+        h_data: &[u8; BME280_H_CALIB_DATA_LEN],
+    ) -> Self {
+        parse_calib_data(pt_data, h_data)
+    }
+
+    /// Serializes the calibration coefficients and user corrections, e.g. to keep them in
+    /// retained RAM or EEPROM across deep sleep and skip reading them from the sensor after
+    /// waking up with [`Self::from_bytes`] and `init_with_calibration()`.
+    /// The snapshot starts with a format version and ends with a CRC-8 of the preceding bytes;
+    /// all fields are little endian.
+    pub fn to_bytes(&self) -> [u8; CALIBRATION_SNAPSHOT_LEN] {
+        let mut bytes = [0; CALIBRATION_SNAPSHOT_LEN];
+        let mut pos = 0;
+        let mut put = |data: &[u8]| {
+            bytes[pos..pos + data.len()].copy_from_slice(data);
+            pos += data.len();
+        };
+        put(&[CALIBRATION_SNAPSHOT_VERSION]);
+        put(&self.dig_t1.to_le_bytes());
+        put(&self.dig_t2.to_le_bytes());
+        put(&self.dig_t3.to_le_bytes());
+        put(&self.dig_p1.to_le_bytes());
+        put(&self.dig_p2.to_le_bytes());
+        put(&self.dig_p3.to_le_bytes());
+        put(&self.dig_p4.to_le_bytes());
+        put(&self.dig_p5.to_le_bytes());
+        put(&self.dig_p6.to_le_bytes());
+        put(&self.dig_p7.to_le_bytes());
+        put(&self.dig_p8.to_le_bytes());
+        put(&self.dig_p9.to_le_bytes());
+        put(&self.dig_h1.to_le_bytes());
+        put(&self.dig_h2.to_le_bytes());
+        put(&self.dig_h3.to_le_bytes());
+        put(&self.dig_h4.to_le_bytes());
+        put(&self.dig_h5.to_le_bytes());
+        put(&self.dig_h6.to_le_bytes());
+        put(&self.temperature_offset.to_le_bytes());
+        put(&self.humidity_offset.to_le_bytes());
+        put(&self.humidity_gain.to_le_bytes());
+        put(&self.pressure_offset.to_le_bytes());
+        bytes[CALIBRATION_SNAPSHOT_LEN - 1] = crc8(&bytes[..CALIBRATION_SNAPSHOT_LEN - 1]);
+        bytes
+    }
+
+    /// Restores a snapshot serialized with [`Self::to_bytes`].
+    /// This returns `None` if the snapshot has an unknown format version or a wrong checksum,
+    /// e.g. because the storage was erased or corrupted.
+    pub fn from_bytes(bytes: &[u8; CALIBRATION_SNAPSHOT_LEN]) -> Option<Self> {
+        if bytes[0] != CALIBRATION_SNAPSHOT_VERSION
+            || crc8(&bytes[..CALIBRATION_SNAPSHOT_LEN - 1]) != bytes[CALIBRATION_SNAPSHOT_LEN - 1]
+        {
+            return None;
         }
+
+        let mut pos = 1;
+        Some(CalibrationData {
+            dig_t1: u16::from_le_bytes(take(bytes, &mut pos)),
+            dig_t2: i16::from_le_bytes(take(bytes, &mut pos)),
+            dig_t3: i16::from_le_bytes(take(bytes, &mut pos)),
+            dig_p1: u16::from_le_bytes(take(bytes, &mut pos)),
+            dig_p2: i16::from_le_bytes(take(bytes, &mut pos)),
+            dig_p3: i16::from_le_bytes(take(bytes, &mut pos)),
+            dig_p4: i16::from_le_bytes(take(bytes, &mut pos)),
+            dig_p5: i16::from_le_bytes(take(bytes, &mut pos)),
+            dig_p6: i16::from_le_bytes(take(bytes, &mut pos)),
+            dig_p7: i16::from_le_bytes(take(bytes, &mut pos)),
+            dig_p8: i16::from_le_bytes(take(bytes, &mut pos)),
+            dig_p9: i16::from_le_bytes(take(bytes, &mut pos)),
+            dig_h1: u8::from_le_bytes(take(bytes, &mut pos)),
+            dig_h2: i16::from_le_bytes(take(bytes, &mut pos)),
+            dig_h3: u8::from_le_bytes(take(bytes, &mut pos)),
+            dig_h4: i16::from_le_bytes(take(bytes, &mut pos)),
+            dig_h5: i16::from_le_bytes(take(bytes, &mut pos)),
+            dig_h6: i8::from_le_bytes(take(bytes, &mut pos)),
+            temperature_offset: i32::from_le_bytes(take(bytes, &mut pos)),
+            humidity_offset: i32::from_le_bytes(take(bytes, &mut pos)),
+            humidity_gain: u32::from_le_bytes(take(bytes, &mut pos)),
+            pressure_offset: i32::from_le_bytes(take(bytes, &mut pos)),
+            t_fine: 0,
+        })
+    }
+
+    /// Temperature offset saturated to the width of the operating range, so the compensation
+    /// cannot overflow
+    fn clamped_temperature_offset(&self) -> i32 {
+        const MAX_OFFSET: i32 = BME280_TEMP_MAX_FIXED - BME280_TEMP_MIN_FIXED;
+        self.temperature_offset.clamp(-MAX_OFFSET, MAX_OFFSET)
+    }
+
+    /// Temperature offset in `t_fine` units (1/5120 deg C)
+    fn t_fine_offset(&self) -> i32 {
+        self.clamped_temperature_offset() * 256 / 5
+    }
+
+    /// Copies the user supplied corrections from another calibration snapshot
+    fn copy_user_offsets(&mut self, other: &CalibrationData) {
+        self.temperature_offset = other.temperature_offset;
+        self.humidity_offset = other.humidity_offset;
+        self.humidity_gain = other.humidity_gain;
+        self.pressure_offset = other.pressure_offset;
+    }
+
+    /// Applies the user pressure offset to a pressure in Q24.8 format
+    fn correct_pressure_fixed(&self, pressure: i64) -> u32 {
+        let pressure = pressure + (i64::from(self.pressure_offset) << 8);
+        pressure.clamp(
+            i64::from(BME280_PRESSURE_MIN_FIXED),
+            i64::from(BME280_PRESSURE_MAX_FIXED),
+        ) as u32
+    }
+
+    /// Applies the user humidity correction to a humidity in Q22.10 format
+    fn correct_humidity_fixed(&self, humidity: i32) -> i32 {
+        let humidity = (i64::from(humidity) * i64::from(self.humidity_gain)) >> 16;
+        let humidity = humidity + i64::from(self.humidity_offset) * 1024 / 100;
+        humidity.clamp(
+            i64::from(BME280_HUMIDITY_MIN_FIXED),
+            i64::from(BME280_HUMIDITY_MAX_FIXED),
+        ) as i32
     }
 }
 
+/// Reads the next `N` bytes of a calibration snapshot
+fn take<const N: usize>(bytes: &[u8], pos: &mut usize) -> [u8; N] {
+    let mut data = [0; N];
+    data.copy_from_slice(&bytes[*pos..*pos + N]);
+    *pos += N;
+    data
+}
+
+/// CRC-8 with polynomial 0x31 and initial value 0xFF
+fn crc8(data: &[u8]) -> u8 {
+    data.iter().fold(0xFF, |crc, byte| {
+        (0..8).fold(crc ^ byte, |crc, _| {
+            if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x31
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
 fn parse_calib_data(
     pt_data: &[u8; BME280_P_T_CALIB_DATA_LEN],
     h_data: &[u8; BME280_H_CALIB_DATA_LEN],
@@ -971,6 +3366,10 @@ fn parse_calib_data(
         dig_h4,
         dig_h5,
         dig_h6,
+        temperature_offset: 0,
+        humidity_offset: 0,
+        humidity_gain: BME280_HUMIDITY_GAIN_UNITY,
+        pressure_offset: 0,
         t_fine: 0,
     }
 }
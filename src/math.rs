@@ -0,0 +1,161 @@
+//! Math functions that are not available in `core`.
+//!
+//! The float functions are provided by the math backend selected with exactly one of the
+//! `libm` (portable, the default), `micromath` (smallest and fastest, with reduced accuracy) and
+//! `std-math` (the standard library) features, each of which enables `float`.
+//!
+//! The fixed-point functions work on signed values in Q2.30 format, with the integer part
+//! widened to 34 bits by the `i64` representation.
+
+/// 1.0 in Q2.30 format
+pub(crate) const ONE_Q30: i64 = 1 << 30;
+/// ln(2) in Q2.30 format
+const LN_2_Q30: i64 = 744_261_118;
+
+#[cfg(any(
+    all(feature = "libm", feature = "micromath"),
+    all(feature = "libm", feature = "std-math"),
+    all(feature = "micromath", feature = "std-math"),
+))]
+compile_error!(
+    "the math backend features `libm`, `micromath` and `std-math` are mutually exclusive"
+);
+
+#[cfg(all(
+    feature = "float",
+    not(any(feature = "libm", feature = "micromath", feature = "std-math"))
+))]
+compile_error!(
+    "the `float` feature needs a math backend, enable `libm`, `micromath` or `std-math`"
+);
+
+#[cfg(feature = "micromath")]
+mod backend {
+    use micromath::F32Ext;
+
+    pub(crate) fn powf(x: f32, y: f32) -> f32 {
+        F32Ext::powf(x, y)
+    }
+
+    pub(crate) fn lnf(x: f32) -> f32 {
+        F32Ext::ln(x)
+    }
+
+    pub(crate) fn expf(x: f32) -> f32 {
+        F32Ext::exp(x)
+    }
+
+    pub(crate) fn sqrtf(x: f32) -> f32 {
+        F32Ext::sqrt(x)
+    }
+
+    #[cfg(feature = "simulator")]
+    pub(crate) fn roundf(x: f32) -> f32 {
+        F32Ext::round(x)
+    }
+}
+
+#[cfg(all(feature = "std-math", not(feature = "micromath")))]
+mod backend {
+    pub(crate) fn powf(x: f32, y: f32) -> f32 {
+        x.powf(y)
+    }
+
+    pub(crate) fn lnf(x: f32) -> f32 {
+        x.ln()
+    }
+
+    pub(crate) fn expf(x: f32) -> f32 {
+        x.exp()
+    }
+
+    pub(crate) fn sqrtf(x: f32) -> f32 {
+        x.sqrt()
+    }
+
+    #[cfg(feature = "simulator")]
+    pub(crate) fn roundf(x: f32) -> f32 {
+        x.round()
+    }
+}
+
+#[cfg(all(
+    feature = "libm",
+    not(any(feature = "micromath", feature = "std-math"))
+))]
+mod backend {
+    pub(crate) fn powf(x: f32, y: f32) -> f32 {
+        libm::powf(x, y)
+    }
+
+    pub(crate) fn lnf(x: f32) -> f32 {
+        libm::logf(x)
+    }
+
+    pub(crate) fn expf(x: f32) -> f32 {
+        libm::expf(x)
+    }
+
+    pub(crate) fn sqrtf(x: f32) -> f32 {
+        libm::sqrtf(x)
+    }
+
+    #[cfg(feature = "simulator")]
+    pub(crate) fn roundf(x: f32) -> f32 {
+        libm::roundf(x)
+    }
+}
+
+#[cfg(all(
+    feature = "simulator",
+    any(feature = "libm", feature = "micromath", feature = "std-math")
+))]
+pub(crate) use backend::roundf;
+#[cfg(any(feature = "libm", feature = "micromath", feature = "std-math"))]
+pub(crate) use backend::{expf, lnf, powf, sqrtf};
+
+/// Natural logarithm in Q2.30 format. Arguments below the smallest positive value saturate.
+pub(crate) fn ln_q30(x: i64) -> i64 {
+    let mut x = x.max(1);
+    let mut k = 0;
+    while x >= 2 * ONE_Q30 {
+        x >>= 1;
+        k += 1;
+    }
+    while x < ONE_Q30 {
+        x <<= 1;
+        k -= 1;
+    }
+
+    // ln(x) = 2 * atanh((x - 1) / (x + 1)), with x in [1, 2) the series argument is below 1/3
+    let z = ((x - ONE_Q30) << 30) / (x + ONE_Q30);
+    let z2 = (z * z) >> 30;
+    let mut sum = 0;
+    let mut term = z;
+    let mut n = 1;
+    while term != 0 {
+        sum += term / n;
+        term = (term * z2) >> 30;
+        n += 2;
+    }
+
+    2 * sum + k * LN_2_Q30
+}
+
+/// Exponential function in Q2.30 format. The argument must be below 20 to avoid overflows.
+pub(crate) fn exp_q30(y: i64) -> i64 {
+    // exp(y) = 2^k * exp(r), with r in [0, ln(2))
+    let k = y.div_euclid(LN_2_Q30);
+    let r = y - k * LN_2_Q30;
+
+    let mut sum = ONE_Q30;
+    let mut term = ONE_Q30;
+    let mut n = 1;
+    while term != 0 {
+        term = ((term * r) >> 30) / n;
+        sum += term;
+        n += 1;
+    }
+
+    if k >= 0 { sum << k } else { sum >> -k }
+}
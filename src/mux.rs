@@ -0,0 +1,84 @@
+//! Support for sensors behind a TCA9548A I²C multiplexer, e.g. arrays of BME280s sharing the
+//! address `0x76`.
+//!
+//! Each [`MuxChannel`] selects its multiplexer channel before every transaction, so several
+//! drivers can share the bus through e.g. `embedded-hal-bus` without hand-rolled channel
+//! switching:
+//!
+//! ```ignore
+//! let bus = RefCell::new(i2c);
+//! let mut sensors = [0, 1, 2].map(|channel| {
+//!     let i2c = MuxChannel::new(RefCellDevice::new(&bus), TCA9548A_ADDRESS, channel).unwrap();
+//!     BME280::new_primary(i2c)
+//! });
+//! ```
+
+use embedded_hal::i2c::{ErrorType, Operation, SevenBitAddress};
+
+/// Default address of a TCA9548A, with all address pins low
+pub const TCA9548A_ADDRESS: u8 = 0x70;
+
+/// Number of channels of a TCA9548A
+pub const TCA9548A_CHANNELS: u8 = 8;
+
+/// I²C device selecting a TCA9548A channel before each transaction
+/// The channel selection and the transaction are separate bus transactions, so a bus shared
+/// between threads must not let another device switch channels in between.
+#[derive(Debug, Default)]
+pub struct MuxChannel<I2C> {
+    i2c: I2C,
+    mux_address: u8,
+    channel: u8,
+}
+
+impl<I2C> MuxChannel<I2C> {
+    /// Creates a device for the given channel of the multiplexer at `mux_address`, or `None` if
+    /// the channel does not exist
+    pub fn new(i2c: I2C, mux_address: u8, channel: u8) -> Option<Self> {
+        (channel < TCA9548A_CHANNELS).then_some(Self {
+            i2c,
+            mux_address,
+            channel,
+        })
+    }
+
+    /// Returns the multiplexer channel
+    pub fn channel(&self) -> u8 {
+        self.channel
+    }
+
+    /// Releases the wrapped bus
+    pub fn into_inner(self) -> I2C {
+        self.i2c
+    }
+}
+
+impl<I2C: ErrorType> ErrorType for MuxChannel<I2C> {
+    type Error = I2C::Error;
+}
+
+#[cfg(feature = "sync")]
+impl<I2C: embedded_hal::i2c::I2c> embedded_hal::i2c::I2c for MuxChannel<I2C> {
+    fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.i2c.write(self.mux_address, &[1 << self.channel])?;
+        self.i2c.transaction(address, operations)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I2C: embedded_hal_async::i2c::I2c> embedded_hal_async::i2c::I2c for MuxChannel<I2C> {
+    async fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.i2c
+            .write(self.mux_address, &[1 << self.channel])
+            .await?;
+        self.i2c.transaction(address, operations).await
+    }
+}
@@ -0,0 +1,103 @@
+//! Async facade over the blocking drivers for tokio, running every bus access on the blocking
+//! thread pool with [`spawn_blocking`], so e.g. a web service can poll a sensor on Linux without
+//! stalling its runtime threads:
+//!
+//! ```ignore
+//! let bme280 = Offloaded::new(BME280::new_primary(I2cdev::new("/dev/i2c-1")?), Delay);
+//! bme280.init().await?;
+//! let measurements = bme280.measure().await?;
+//! ```
+
+use std::panic;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use tokio::task::spawn_blocking;
+
+use crate::forward::forward_methods;
+
+/// Blocking driver and its delay, shared behind a mutex so the facade can be cloned into
+/// several tasks.
+/// Calls from different clones are serialized.
+#[derive(Debug)]
+pub struct Offloaded<B, D> {
+    state: Arc<Mutex<(B, D)>>,
+}
+
+impl<B, D> Clone for Offloaded<B, D> {
+    fn clone(&self) -> Self {
+        Self {
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+impl<B, D> Offloaded<B, D>
+where
+    B: Send + 'static,
+    D: Send + 'static,
+{
+    /// Wraps a blocking driver and the delay it uses
+    pub fn new(bme280: B, delay: D) -> Self {
+        Self {
+            state: Arc::new(Mutex::new((bme280, delay))),
+        }
+    }
+
+    /// Runs `f` with the driver and the delay on the blocking thread pool, e.g. to call the
+    /// methods not covered by the facade.
+    /// A panic in `f` is resumed in the calling task.
+    pub async fn run<T, F>(&self, f: F) -> T
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut B, &mut D) -> T + Send + 'static,
+    {
+        let state = Arc::clone(&self.state);
+        let task = spawn_blocking(move || {
+            let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+            let (bme280, delay) = &mut *state;
+            f(bme280, delay)
+        });
+        match task.await {
+            Ok(output) => output,
+            Err(error) => panic::resume_unwind(error.into_panic()),
+        }
+    }
+}
+
+/// Runs the driver methods on the blocking thread pool
+macro_rules! offloaded {
+    ($this:ident, $delay:ident, $method:ident($($arg:expr),*)) => {
+        $this
+            .run(move |bme280, delay| bme280.$method(delay $(, $arg)*))
+            .await
+    };
+    ($this:ident, $method:ident($($arg:expr),*)) => {
+        $this.run(move |bme280, _| bme280.$method($($arg),*)).await
+    };
+}
+
+macro_rules! impl_offloaded {
+    ($driver:ty, $bus:ident: $bus_trait:path, $error:ty) => {
+        forward_methods! {
+            impl[$bus, D] Offloaded<$driver, D> where [
+                $bus: $bus_trait + Send + 'static,
+                $error: Send + 'static,
+                D: embedded_hal::delay::DelayNs + Send + 'static,
+            ];
+            receiver: [&], async: [async], delay: [], format: [+ Send + 'static], error: $error,
+            call: offloaded;
+            setup, measure, control, read
+        }
+    };
+}
+
+impl_offloaded!(
+    crate::i2c::BME280<I2C>,
+    I2C: embedded_hal::i2c::I2c,
+    I2C::Error
+);
+impl_offloaded!(
+    crate::spi::BME280<SPI>,
+    SPI: embedded_hal::spi::SpiDevice,
+    SPI::Error
+);
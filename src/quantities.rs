@@ -0,0 +1,105 @@
+//! Conversions of measurements into [`uom`] quantities, for unit safety in downstream code.
+//!
+//! ```ignore
+//! let quantities = Quantities::from(bme280.measure(&mut delay)?);
+//! let kelvin = quantities.temperature.get::<uom::si::thermodynamic_temperature::kelvin>();
+//! ```
+
+use uom::si::pressure::pascal;
+use uom::si::ratio::percent;
+use uom::si::thermodynamic_temperature::degree_celsius;
+
+#[cfg(feature = "double-precision")]
+use crate::MeasurementsDouble;
+#[cfg(feature = "fixed")]
+use crate::MeasurementsFixed;
+use crate::{Measurements, MeasurementsFixedRaw};
+
+/// Measurement data as single precision quantities
+#[derive(Debug, Clone, Copy)]
+pub struct Quantities {
+    /// temperature
+    pub temperature: uom::si::f32::ThermodynamicTemperature,
+    /// pressure (`None` if skipped)
+    pub pressure: Option<uom::si::f32::Pressure>,
+    /// relative humidity (`None` with BMP280 or if skipped)
+    pub humidity: Option<uom::si::f32::Ratio>,
+}
+
+impl Quantities {
+    fn new(temperature: f32, pressure: Option<f32>, humidity: Option<f32>) -> Self {
+        Self {
+            temperature: uom::si::f32::ThermodynamicTemperature::new::<degree_celsius>(temperature),
+            pressure: pressure.map(uom::si::f32::Pressure::new::<pascal>),
+            humidity: humidity.map(uom::si::f32::Ratio::new::<percent>),
+        }
+    }
+
+    fn from_fixed(temperature: i32, pressure: Option<u32>, humidity: Option<u32>) -> Self {
+        Self::new(
+            temperature as f32 / 100.0,
+            pressure.map(|pressure| pressure as f32 / 256.0),
+            humidity.map(|humidity| humidity as f32 / 1024.0),
+        )
+    }
+}
+
+impl<E> From<Measurements<E>> for Quantities {
+    fn from(measurements: Measurements<E>) -> Self {
+        Self::new(
+            measurements.temperature,
+            measurements.pressure,
+            measurements.humidity,
+        )
+    }
+}
+
+impl<E> From<MeasurementsFixedRaw<E>> for Quantities {
+    fn from(measurements: MeasurementsFixedRaw<E>) -> Self {
+        Self::from_fixed(
+            measurements.temperature,
+            measurements.pressure,
+            measurements.humidity,
+        )
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl<E> From<MeasurementsFixed<E>> for Quantities {
+    fn from(measurements: MeasurementsFixed<E>) -> Self {
+        Self::from_fixed(
+            measurements.temperature,
+            measurements.pressure.map(|pressure| pressure.to_bits()),
+            measurements.humidity.map(|humidity| humidity.to_bits()),
+        )
+    }
+}
+
+/// Measurement data as double precision quantities
+#[cfg(feature = "double-precision")]
+#[derive(Debug, Clone, Copy)]
+pub struct QuantitiesDouble {
+    /// temperature
+    pub temperature: uom::si::f64::ThermodynamicTemperature,
+    /// pressure (`None` if skipped)
+    pub pressure: Option<uom::si::f64::Pressure>,
+    /// relative humidity (`None` with BMP280 or if skipped)
+    pub humidity: Option<uom::si::f64::Ratio>,
+}
+
+#[cfg(feature = "double-precision")]
+impl<E> From<MeasurementsDouble<E>> for QuantitiesDouble {
+    fn from(measurements: MeasurementsDouble<E>) -> Self {
+        Self {
+            temperature: uom::si::f64::ThermodynamicTemperature::new::<degree_celsius>(
+                measurements.temperature,
+            ),
+            pressure: measurements
+                .pressure
+                .map(uom::si::f64::Pressure::new::<pascal>),
+            humidity: measurements
+                .humidity
+                .map(uom::si::f64::Ratio::new::<percent>),
+        }
+    }
+}
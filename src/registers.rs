@@ -0,0 +1,272 @@
+//! Register map of the BME280 and BMP280, see section 5 of the datasheet.
+//!
+//! The addresses and the typed views of the control and status registers are the ones used by
+//! the driver, so they can be relied on for direct register access, e.g. to decode a register
+//! dump:
+//!
+//! ```ignore
+//! let ctrl_meas = CtrlMeas::from_bits(dump[registers::CTRL_MEAS as usize]);
+//! assert_eq!(ctrl_meas.mode(), SensorMode::Sleep);
+//! ```
+//!
+//! The register layer is deliberately hand-written rather than generated by a declarative
+//! toolkit such as `device-driver`: the bus interfaces only need a few block reads and single
+//! register writes, the sync and async variants already come from one source through
+//! `maybe-async-cfg`, and a new register only needs its address here and, if it has fields, a
+//! typed view next to the ones below.
+
+use crate::{IIRFilter, Oversampling, SensorMode, StandbyTime};
+
+/// Chip identification number, see [`ChipModel`](crate::ChipModel)
+pub const CHIP_ID: u8 = 0xD0;
+/// Soft reset, resetting the sensor when [`SOFT_RESET_CMD`] is written
+pub const RESET: u8 = 0xE0;
+/// Humidity oversampling, see [`CtrlHum`]
+pub const CTRL_HUM: u8 = 0xF2;
+/// Conversion status, see [`Status`]
+pub const STATUS: u8 = 0xF3;
+/// Temperature and pressure oversampling and power mode, see [`CtrlMeas`]
+pub const CTRL_MEAS: u8 = 0xF4;
+/// Standby time, IIR filter and 3-wire SPI, see [`Config`]
+pub const CONFIG: u8 = 0xF5;
+/// First of the 8 data registers: pressure, temperature and humidity, most significant byte
+/// first
+pub const DATA: u8 = 0xF7;
+/// Number of data registers starting at [`DATA`]
+pub const DATA_LEN: usize = 8;
+/// First of the 3 temperature data registers
+pub const TEMPERATURE_DATA: u8 = 0xFA;
+/// First of the 26 temperature, pressure and `dig_H1` calibration registers
+pub const CALIB_PT: u8 = 0x88;
+/// First of the 7 remaining humidity calibration registers
+pub const CALIB_H: u8 = 0xE1;
+
+/// Value written to [`RESET`] to reset the sensor
+pub const SOFT_RESET_CMD: u8 = 0xB6;
+
+/// Mode bits of [`CtrlMeas`] for sleep mode
+pub(crate) const MODE_SLEEP: u8 = 0x00;
+/// Mode bits of [`CtrlMeas`] for forced mode; `0x02` also selects forced mode
+pub(crate) const MODE_FORCED: u8 = 0x01;
+/// Mode bits of [`CtrlMeas`] for normal mode
+pub(crate) const MODE_NORMAL: u8 = 0x03;
+
+/// Humidity control register.
+/// Changes only become effective after a write to [`CTRL_MEAS`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CtrlHum(u8);
+
+impl CtrlHum {
+    /// Humidity oversampling bits
+    pub const OSRS_H_MASK: u8 = 0x07;
+
+    /// Wraps the register contents
+    pub fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the register contents
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns the humidity oversampling
+    pub fn humidity_oversampling(&self) -> Oversampling {
+        Oversampling::from_bits(self.0 & Self::OSRS_H_MASK)
+    }
+
+    /// Sets the humidity oversampling
+    pub fn with_humidity_oversampling(self, oversampling: Oversampling) -> Self {
+        Self(set_bits!(self.0, Self::OSRS_H_MASK, 0, oversampling.bits()))
+    }
+}
+
+/// Measurement control register
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CtrlMeas(u8);
+
+impl CtrlMeas {
+    /// Temperature oversampling bits
+    pub const OSRS_T_MASK: u8 = 0xE0;
+    /// Position of the temperature oversampling bits
+    pub const OSRS_T_POS: u8 = 5;
+    /// Pressure oversampling bits
+    pub const OSRS_P_MASK: u8 = 0x1C;
+    /// Position of the pressure oversampling bits
+    pub const OSRS_P_POS: u8 = 2;
+    /// Power mode bits
+    pub const MODE_MASK: u8 = 0x03;
+
+    /// Wraps the register contents
+    pub fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the register contents
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns the temperature oversampling
+    pub fn temperature_oversampling(&self) -> Oversampling {
+        Oversampling::from_bits(get_bits!(self.0, Self::OSRS_T_MASK, Self::OSRS_T_POS))
+    }
+
+    /// Returns the pressure oversampling
+    pub fn pressure_oversampling(&self) -> Oversampling {
+        Oversampling::from_bits(get_bits!(self.0, Self::OSRS_P_MASK, Self::OSRS_P_POS))
+    }
+
+    /// Returns the power mode
+    pub fn mode(&self) -> SensorMode {
+        match self.0 & Self::MODE_MASK {
+            MODE_SLEEP => SensorMode::Sleep,
+            MODE_NORMAL => SensorMode::Normal,
+            _ => SensorMode::Forced,
+        }
+    }
+
+    /// Sets the temperature oversampling
+    pub fn with_temperature_oversampling(self, oversampling: Oversampling) -> Self {
+        Self(set_bits!(
+            self.0,
+            Self::OSRS_T_MASK,
+            Self::OSRS_T_POS,
+            oversampling.bits()
+        ))
+    }
+
+    /// Sets the pressure oversampling
+    pub fn with_pressure_oversampling(self, oversampling: Oversampling) -> Self {
+        Self(set_bits!(
+            self.0,
+            Self::OSRS_P_MASK,
+            Self::OSRS_P_POS,
+            oversampling.bits()
+        ))
+    }
+
+    /// Sets the power mode
+    pub fn with_mode(self, mode: SensorMode) -> Self {
+        let bits = match mode {
+            SensorMode::Sleep => MODE_SLEEP,
+            SensorMode::Forced => MODE_FORCED,
+            SensorMode::Normal => MODE_NORMAL,
+        };
+        Self(set_bits!(self.0, Self::MODE_MASK, 0, bits))
+    }
+}
+
+/// Configuration register.
+/// Writes in normal mode may be ignored, so the driver only writes it in sleep mode.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Config(u8);
+
+impl Config {
+    /// Standby time bits
+    pub const T_SB_MASK: u8 = 0xE0;
+    /// Position of the standby time bits
+    pub const T_SB_POS: u8 = 5;
+    /// IIR filter bits
+    pub const FILTER_MASK: u8 = 0x1C;
+    /// Position of the IIR filter bits
+    pub const FILTER_POS: u8 = 2;
+    /// 3-wire SPI enable bit
+    pub const SPI3W_EN_MASK: u8 = 0x01;
+    /// Bits that can be written; bit 1 is reserved
+    pub const WRITABLE_MASK: u8 = 0xFD;
+
+    /// Wraps the register contents
+    pub fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the register contents
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns the normal mode standby time
+    pub fn standby_time(&self) -> StandbyTime {
+        StandbyTime::from_bits(get_bits!(self.0, Self::T_SB_MASK, Self::T_SB_POS))
+    }
+
+    /// Returns the IIR filter coefficient
+    pub fn iir_filter(&self) -> IIRFilter {
+        IIRFilter::from_bits(get_bits!(self.0, Self::FILTER_MASK, Self::FILTER_POS))
+    }
+
+    /// Returns whether 3-wire SPI is enabled
+    pub fn spi3w_enabled(&self) -> bool {
+        self.0 & Self::SPI3W_EN_MASK != 0
+    }
+
+    /// Sets the normal mode standby time
+    pub fn with_standby_time(self, standby_time: StandbyTime) -> Self {
+        Self(set_bits!(
+            self.0,
+            Self::T_SB_MASK,
+            Self::T_SB_POS,
+            standby_time.bits()
+        ))
+    }
+
+    /// Sets the IIR filter coefficient
+    pub fn with_iir_filter(self, filter: IIRFilter) -> Self {
+        Self(set_bits!(
+            self.0,
+            Self::FILTER_MASK,
+            Self::FILTER_POS,
+            filter.bits()
+        ))
+    }
+
+    /// Enables or disables 3-wire SPI
+    pub fn with_spi3w_enabled(self, enabled: bool) -> Self {
+        Self(set_bits!(self.0, Self::SPI3W_EN_MASK, 0, u8::from(enabled)))
+    }
+}
+
+/// Status register
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Status(u8);
+
+impl Status {
+    /// Conversion running bit
+    pub const MEASURING_MASK: u8 = 0x08;
+    /// NVM copy running bit
+    pub const IM_UPDATE_MASK: u8 = 0x01;
+
+    /// Wraps the register contents
+    pub fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the register contents
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns whether a conversion is running
+    pub fn measuring(&self) -> bool {
+        self.0 & Self::MEASURING_MASK != 0
+    }
+
+    /// Returns whether the calibration data is being copied from NVM
+    pub fn im_update(&self) -> bool {
+        self.0 & Self::IM_UPDATE_MASK != 0
+    }
+}
+
+impl From<Status> for crate::Status {
+    fn from(status: Status) -> Self {
+        Self {
+            measuring: status.measuring(),
+            im_update: status.im_update(),
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Retries of failed bus transactions, e.g. for transient NACKs on long cables or during EMI
+//! bursts.
+//!
+//! [`Retry`] wraps the I²C or SPI device handed to the driver, so every register access of the
+//! driver is retried according to its [`RetryPolicy`]:
+//!
+//! ```ignore
+//! let policy = RetryPolicy::new(4, 500).with_backoff(8000);
+//! let mut bme280 = BME280::new_primary(Retry::new(i2c, delay.clone(), policy));
+//! ```
+//!
+//! Only a transaction that fails on every attempt reaches the driver as
+//! [`Error::Bus`](crate::Error::Bus). Retrying is safe for all transactions of the driver: reads
+//! have no side effects, and writes set whole registers.
+
+use embedded_hal::i2c::{ErrorType as I2cErrorType, Operation as I2cOperation, SevenBitAddress};
+use embedded_hal::spi::{ErrorType as SpiErrorType, Operation as SpiOperation};
+
+/// Number of attempts and delays between them
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RetryPolicy {
+    attempts: u8,
+    delay_us: u32,
+    max_delay_us: u32,
+}
+
+/// Three attempts, one millisecond apart
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3, 1000)
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy making up to `attempts` attempts, including the first one, waiting
+    /// `delay_us` microseconds between them.
+    /// An `attempts` of 0 is treated as 1, i.e. no retries.
+    pub const fn new(attempts: u8, delay_us: u32) -> Self {
+        Self {
+            attempts: if attempts == 0 { 1 } else { attempts },
+            delay_us,
+            max_delay_us: delay_us,
+        }
+    }
+
+    /// Doubles the delay after each failed retry, up to `max_delay_us` microseconds
+    pub const fn with_backoff(self, max_delay_us: u32) -> Self {
+        Self {
+            max_delay_us,
+            ..self
+        }
+    }
+
+    /// Returns the maximum number of attempts, including the first one
+    pub fn attempts(&self) -> u8 {
+        self.attempts
+    }
+
+    /// Returns the delay in microseconds before the first retry
+    pub fn delay_us(&self) -> u32 {
+        self.delay_us
+    }
+
+    /// Returns the delay before the retry following a delay of `delay_us`
+    fn next_delay_us(&self, delay_us: u32) -> u32 {
+        delay_us
+            .saturating_mul(2)
+            .min(self.max_delay_us)
+            .max(delay_us)
+    }
+}
+
+/// I²C or SPI device retrying failed transactions according to a [`RetryPolicy`]
+#[derive(Debug, Default)]
+pub struct Retry<BUS, D> {
+    bus: BUS,
+    delay: D,
+    policy: RetryPolicy,
+    retries: u32,
+}
+
+impl<BUS, D> Retry<BUS, D> {
+    /// Wraps a device, waiting with `delay` between attempts
+    pub fn new(bus: BUS, delay: D, policy: RetryPolicy) -> Self {
+        Self {
+            bus,
+            delay,
+            policy,
+            retries: 0,
+        }
+    }
+
+    /// Returns the retry policy
+    pub fn policy(&self) -> RetryPolicy {
+        self.policy
+    }
+
+    /// Returns the number of transactions repeated after a failure.
+    /// The counter wraps around on overflow.
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+
+    /// Releases the wrapped device and the delay
+    pub fn into_inner(self) -> (BUS, D) {
+        (self.bus, self.delay)
+    }
+}
+
+impl<BUS: I2cErrorType, D> I2cErrorType for Retry<BUS, D> {
+    type Error = BUS::Error;
+}
+
+impl<BUS: SpiErrorType, D> SpiErrorType for Retry<BUS, D> {
+    type Error = BUS::Error;
+}
+
+/// Runs `$transaction` until it succeeds or the attempts of the policy are exhausted
+macro_rules! retry {
+    ($self:ident, $transaction:expr $(, $await:tt)?) => {{
+        let mut delay_us = $self.policy.delay_us;
+        let mut attempt = 1;
+        loop {
+            match $transaction {
+                Err(_) if attempt < $self.policy.attempts => {
+                    $self.delay.delay_us(delay_us)$(.$await)?;
+                    delay_us = $self.policy.next_delay_us(delay_us);
+                    $self.retries = $self.retries.wrapping_add(1);
+                    attempt += 1;
+                }
+                result => break result,
+            }
+        }
+    }};
+}
+
+#[cfg(feature = "sync")]
+impl<BUS, D> embedded_hal::i2c::I2c for Retry<BUS, D>
+where
+    BUS: embedded_hal::i2c::I2c,
+    D: embedded_hal::delay::DelayNs,
+{
+    fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [I2cOperation<'_>],
+    ) -> Result<(), Self::Error> {
+        retry!(self, self.bus.transaction(address, operations))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<BUS, D> embedded_hal_async::i2c::I2c for Retry<BUS, D>
+where
+    BUS: embedded_hal_async::i2c::I2c,
+    D: embedded_hal_async::delay::DelayNs,
+{
+    async fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [I2cOperation<'_>],
+    ) -> Result<(), Self::Error> {
+        retry!(self, self.bus.transaction(address, operations).await, await)
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<BUS, D> embedded_hal::spi::SpiDevice for Retry<BUS, D>
+where
+    BUS: embedded_hal::spi::SpiDevice,
+    D: embedded_hal::delay::DelayNs,
+{
+    fn transaction(&mut self, operations: &mut [SpiOperation<'_, u8>]) -> Result<(), Self::Error> {
+        retry!(self, self.bus.transaction(operations))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<BUS, D> embedded_hal_async::spi::SpiDevice for Retry<BUS, D>
+where
+    BUS: embedded_hal_async::spi::SpiDevice,
+    D: embedded_hal_async::delay::DelayNs,
+{
+    async fn transaction(
+        &mut self,
+        operations: &mut [SpiOperation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        retry!(self, self.bus.transaction(operations).await, await)
+    }
+}
@@ -0,0 +1,95 @@
+//! Implementations of the [`embedded-sensors-hal`](embedded_sensors_hal) and
+//! [`embedded-sensors-hal-async`](embedded_sensors_hal_async) sensor traits.
+//!
+//! The traits read the most recent conversion without triggering a new one, so the sensor is
+//! meant to run in normal mode, e.g. after `bme280.start_normal_mode()`.
+//! The crates do not define a pressure sensor trait yet, so only the temperature and relative
+//! humidity traits are implemented.
+
+use core::fmt::Debug;
+
+use embedded_sensors_hal::sensor::{self, ErrorKind};
+
+use crate::Error;
+
+impl<E: Debug> sensor::Error for Error<E> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Bus(_) => ErrorKind::Peripheral,
+            Error::NoCalibrationData | Error::Timeout => ErrorKind::NotReady,
+            Error::ImplausibleMeasurement => ErrorKind::Saturated,
+            Error::UnsupportedChip => ErrorKind::InvalidInput,
+            Error::CompensationFailed
+            | Error::InvalidData
+            | Error::Delay
+            | Error::WriteVerificationFailed => ErrorKind::Other,
+        }
+    }
+}
+
+macro_rules! impl_sensor_traits {
+    ($driver:ty, $bus:ident: $bus_trait:path, $error:ty) => {
+        impl<$bus: $bus_trait> sensor::ErrorType for $driver {
+            type Error = Error<$error>;
+        }
+
+        impl<$bus: $bus_trait> embedded_sensors_hal::temperature::TemperatureSensor for $driver {
+            fn temperature(&mut self) -> Result<f32, Self::Error> {
+                Ok(self.read_measurement()?.temperature)
+            }
+        }
+
+        /// Returns [`Error::InvalidData`] with a BMP280 or if the humidity is skipped.
+        impl<$bus: $bus_trait> embedded_sensors_hal::humidity::RelativeHumiditySensor for $driver {
+            fn relative_humidity(&mut self) -> Result<f32, Self::Error> {
+                self.read_measurement()?.humidity.ok_or(Error::InvalidData)
+            }
+        }
+    };
+    (async $driver:ty, $bus:ident: $bus_trait:path, $error:ty) => {
+        impl<$bus: $bus_trait> sensor::ErrorType for $driver {
+            type Error = Error<$error>;
+        }
+
+        impl<$bus: $bus_trait> embedded_sensors_hal_async::temperature::TemperatureSensor
+            for $driver
+        {
+            async fn temperature(&mut self) -> Result<f32, Self::Error> {
+                Ok(self.read_measurement().await?.temperature)
+            }
+        }
+
+        /// Returns [`Error::InvalidData`] with a BMP280 or if the humidity is skipped.
+        impl<$bus: $bus_trait> embedded_sensors_hal_async::humidity::RelativeHumiditySensor
+            for $driver
+        {
+            async fn relative_humidity(&mut self) -> Result<f32, Self::Error> {
+                self.read_measurement()
+                    .await?
+                    .humidity
+                    .ok_or(Error::InvalidData)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "sync")]
+impl_sensor_traits!(crate::i2c::BME280<I2C>, I2C: embedded_hal::i2c::I2c, I2C::Error);
+#[cfg(feature = "sync")]
+impl_sensor_traits!(
+    crate::spi::BME280<SPI>,
+    SPI: embedded_hal::spi::SpiDevice,
+    SPI::Error
+);
+#[cfg(feature = "async")]
+impl_sensor_traits!(
+    async crate::i2c::AsyncBME280<I2C>,
+    I2C: embedded_hal_async::i2c::I2c,
+    I2C::Error
+);
+#[cfg(feature = "async")]
+impl_sensor_traits!(
+    async crate::spi::AsyncBME280<SPI>,
+    SPI: embedded_hal_async::spi::SpiDevice,
+    SPI::Error
+);
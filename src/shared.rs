@@ -0,0 +1,82 @@
+//! Async driver shared between tasks behind an [`embassy_sync`] mutex:
+//!
+//! ```ignore
+//! static BME280: StaticCell<SharedBme280<CriticalSectionRawMutex, AsyncBME280<I2c>>> =
+//!     StaticCell::new();
+//! let bme280 = BME280.init(SharedBme280::new(AsyncBME280::new_primary(i2c)));
+//! bme280.init(&mut Delay).await?;
+//! // in any task holding a `&SharedBme280`
+//! let measurements = bme280.measure(&mut Delay).await?;
+//! ```
+//!
+//! Each call locks the driver until it completes, so the tasks take turns on the sensor.
+//! Sequences of calls that must not interleave with other tasks run under [`SharedBme280::lock`].
+
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::mutex::{Mutex, MutexGuard};
+use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
+
+use crate::forward::forward_methods;
+
+/// Async driver behind a mutex, usable through a shared reference
+pub struct SharedBme280<R: RawMutex, B> {
+    bme280: Mutex<R, B>,
+}
+
+/// The driver is not shown, since formatting it would need the lock.
+impl<R: RawMutex, B> core::fmt::Debug for SharedBme280<R, B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SharedBme280").finish_non_exhaustive()
+    }
+}
+
+impl<R: RawMutex, B> SharedBme280<R, B> {
+    /// Wraps an async driver
+    pub const fn new(bme280: B) -> Self {
+        Self {
+            bme280: Mutex::new(bme280),
+        }
+    }
+
+    /// Locks the driver, giving exclusive access until the guard is dropped
+    pub async fn lock(&self) -> MutexGuard<'_, R, B> {
+        self.bme280.lock().await
+    }
+
+    /// Releases the wrapped driver
+    pub fn into_inner(self) -> B {
+        self.bme280.into_inner()
+    }
+}
+
+/// Runs the driver methods with the driver locked until they complete
+macro_rules! locked {
+    ($this:ident, $delay:ident, $method:ident($($arg:expr),*)) => {
+        $this.lock().await.$method($delay $(, $arg)*).await
+    };
+    ($this:ident, $method:ident($($arg:expr),*)) => {
+        $this.lock().await.$method($($arg),*).await
+    };
+}
+
+macro_rules! impl_shared {
+    ($driver:ty, $bus:ident: $bus_trait:path, $error:ty) => {
+        forward_methods! {
+            impl[R: RawMutex, $bus: $bus_trait] SharedBme280<R, $driver> where [];
+            receiver: [&], async: [async], delay: [D: AsyncDelayNs], format: [], error: $error,
+            call: locked;
+            setup, measure, control, read
+        }
+    };
+}
+
+impl_shared!(
+    crate::i2c::AsyncBME280<I2C>,
+    I2C: embedded_hal_async::i2c::I2c,
+    I2C::Error
+);
+impl_shared!(
+    crate::spi::AsyncBME280<SPI>,
+    SPI: embedded_hal_async::spi::SpiDevice,
+    SPI::Error
+);
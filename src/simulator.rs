@@ -0,0 +1,323 @@
+//! Software BME280 behind a simulated I²C bus, for testing applications on the host without
+//! hardware:
+//!
+//! ```ignore
+//! let mut sensor = SimulatedBme280::new(0x76);
+//! sensor.set_temperature(21.5);
+//! let mut bme280 = BME280::new_primary(sensor);
+//! bme280.init(&mut delay)?;
+//! let measurements = bme280.measure(&mut delay)?;
+//! ```
+//!
+//! The simulated sensor exposes the chip id, calibration, control and data registers of a
+//! BME280. Forced conversions complete instantly, and their raw values are computed from the
+//! programmed temperature, pressure and humidity with the inverse of the compensation, so the
+//! driver reads back the programmed values up to the resolution of the sensor.
+//! In normal mode, the measuring bit of the status register toggles on every read of the status
+//! register, and a conversion completes each time it clears.
+
+use embedded_hal::i2c::{ErrorKind, ErrorType, NoAcknowledgeSource, Operation, SevenBitAddress};
+
+#[cfg(feature = "float")]
+use crate::math;
+use crate::{
+    BME280_CHIP_ID, BME280_CHIP_ID_ADDR, BME280_CONFIG_ADDR, BME280_CTRL_HUM_ADDR,
+    BME280_CTRL_HUM_MSK, BME280_CTRL_MEAS_ADDR, BME280_DATA_ADDR, BME280_H_CALIB_DATA_ADDR,
+    BME280_H_CALIB_DATA_LEN, BME280_NORMAL_MODE, BME280_P_T_CALIB_DATA_ADDR,
+    BME280_P_T_CALIB_DATA_LEN, BME280_RESET_ADDR, BME280_SENSOR_MODE_MSK, BME280_SKIPPED_HUMIDITY,
+    BME280_SKIPPED_PRESSURE, BME280_SKIPPED_TEMPERATURE, BME280_SLEEP_MODE, BME280_SOFT_RESET_CMD,
+    BME280_STATUS_ADDR, CalibrationData, RawMeasurements, compensate_fixed_raw, registers,
+};
+
+/// Temperature and pressure calibration registers, the 26 bytes starting at 0x88.
+/// The coefficients are those of the compensation example in the datasheet.
+pub const CALIBRATION_PT: [u8; BME280_P_T_CALIB_DATA_LEN] = [
+    0x70, 0x6B, 0x43, 0x67, 0x18, 0xFC, 0x7D, 0x8E, 0x43, 0xD6, 0xD0, 0x0B, 0x27, 0x0B, 0x8C, 0x00,
+    0xF9, 0xFF, 0x8C, 0x3C, 0xF8, 0xC6, 0x70, 0x17, 0x00, 0x4B,
+];
+
+/// Humidity calibration registers, the 7 bytes starting at 0xE1, with coefficients typical of
+/// production sensors
+pub const CALIBRATION_H: [u8; BME280_H_CALIB_DATA_LEN] = [0x6A, 0x01, 0x00, 0x13, 0x29, 0x03, 0x1E];
+
+/// Largest raw temperature and pressure ADC value
+const MAX_RAW_PT: u32 = 0xFFFFF;
+/// Largest raw humidity ADC value
+const MAX_RAW_H: u32 = 0xFFFF;
+
+/// Simulated BME280 implementing the I²C bus traits
+#[derive(Debug, Clone)]
+pub struct SimulatedBme280 {
+    address: u8,
+    registers: [u8; 256],
+    /// register accessed by the next read
+    pointer: u8,
+    /// whether a normal mode conversion is running
+    measuring: bool,
+    calibration: CalibrationData,
+    /// temperature in hundredths of degrees celsius
+    temperature: i32,
+    /// pressure in pascals in Q24.8 format
+    pressure: u32,
+    /// percent relative humidity in Q22.10 format
+    humidity: u32,
+}
+
+impl SimulatedBme280 {
+    /// Creates a sensor answering at the given I²C address, in sleep mode after power on.
+    /// It reports 25 deg C, 1013.25 hPa and 50 % relative humidity until programmed otherwise.
+    pub fn new(address: u8) -> Self {
+        let mut registers = [0; 256];
+        registers[BME280_CHIP_ID_ADDR as usize] = BME280_CHIP_ID;
+        let pt = BME280_P_T_CALIB_DATA_ADDR as usize;
+        registers[pt..pt + BME280_P_T_CALIB_DATA_LEN].copy_from_slice(&CALIBRATION_PT);
+        let h = BME280_H_CALIB_DATA_ADDR as usize;
+        registers[h..h + BME280_H_CALIB_DATA_LEN].copy_from_slice(&CALIBRATION_H);
+
+        let mut sensor = Self {
+            address,
+            registers,
+            pointer: 0,
+            measuring: false,
+            calibration: CalibrationData::from_registers(&CALIBRATION_PT, &CALIBRATION_H),
+            temperature: 2500,
+            pressure: 101_325 << 8,
+            humidity: 50 << 10,
+        };
+        sensor.reset();
+        sensor
+    }
+
+    /// Sets the temperature in degrees celsius
+    #[cfg(feature = "float")]
+    pub fn set_temperature(&mut self, celsius: f32) {
+        self.set_temperature_fixed(math::roundf(celsius * 100.0) as i32);
+    }
+
+    /// Sets the pressure in pascals
+    #[cfg(feature = "float")]
+    pub fn set_pressure(&mut self, pa: f32) {
+        self.set_pressure_fixed(math::roundf(pa * 256.0) as u32);
+    }
+
+    /// Sets the percent relative humidity
+    #[cfg(feature = "float")]
+    pub fn set_humidity(&mut self, percent: f32) {
+        self.set_humidity_fixed(math::roundf(percent * 1024.0) as u32);
+    }
+
+    /// Sets the temperature in hundredths of degrees celsius
+    pub fn set_temperature_fixed(&mut self, temperature: i32) {
+        self.temperature = temperature;
+        self.refresh();
+    }
+
+    /// Sets the pressure in pascals in Q24.8 format
+    pub fn set_pressure_fixed(&mut self, pressure: u32) {
+        self.pressure = pressure;
+        self.refresh();
+    }
+
+    /// Sets the percent relative humidity in Q22.10 format
+    pub fn set_humidity_fixed(&mut self, humidity: u32) {
+        self.humidity = humidity;
+        self.refresh();
+    }
+
+    /// Returns the raw ADC values the sensor converts for the programmed environment
+    pub fn raw_measurements(&self) -> RawMeasurements {
+        let calibration = &self.calibration;
+        let compensate = |temperature, pressure, humidity| {
+            let raw = RawMeasurements {
+                temperature,
+                pressure,
+                humidity,
+            };
+            compensate_fixed_raw::<()>(&raw, calibration).ok()
+        };
+
+        let temperature = invert(MAX_RAW_PT, i64::from(self.temperature), |raw| {
+            compensate(raw, BME280_SKIPPED_PRESSURE, BME280_SKIPPED_HUMIDITY)
+                .map(|measurements| i64::from(measurements.temperature))
+        });
+        // the pressure falls as its raw value rises
+        let pressure = invert(MAX_RAW_PT, -i64::from(self.pressure), |raw| {
+            compensate(temperature, raw, BME280_SKIPPED_HUMIDITY)
+                .and_then(|measurements| measurements.pressure)
+                .map(|pressure| -i64::from(pressure))
+        });
+        let humidity = invert(MAX_RAW_H, i64::from(self.humidity), |raw| {
+            compensate(temperature, BME280_SKIPPED_PRESSURE, raw)
+                .and_then(|measurements| measurements.humidity)
+                .map(i64::from)
+        });
+        RawMeasurements {
+            temperature,
+            pressure,
+            humidity,
+        }
+    }
+
+    fn read_register(&mut self, register: u8) -> u8 {
+        if register == BME280_STATUS_ADDR && self.normal_mode() {
+            self.measuring = !self.measuring;
+            if self.measuring {
+                return registers::Status::MEASURING_MASK;
+            }
+            self.convert();
+        }
+        self.registers[register as usize]
+    }
+
+    fn normal_mode(&self) -> bool {
+        let ctrl_meas = self.registers[BME280_CTRL_MEAS_ADDR as usize];
+        ctrl_meas & BME280_SENSOR_MODE_MSK == BME280_NORMAL_MODE
+    }
+
+    fn write_register(&mut self, register: u8, payload: u8) {
+        match register {
+            BME280_RESET_ADDR if payload == BME280_SOFT_RESET_CMD => self.reset(),
+            BME280_CTRL_HUM_ADDR => {
+                self.registers[register as usize] = payload & BME280_CTRL_HUM_MSK;
+            }
+            BME280_CTRL_MEAS_ADDR => {
+                self.registers[register as usize] = payload;
+                self.measuring = false;
+                match payload & BME280_SENSOR_MODE_MSK {
+                    BME280_SLEEP_MODE => {}
+                    BME280_NORMAL_MODE => self.convert(),
+                    _ => {
+                        // forced conversions complete instantly
+                        self.convert();
+                        self.registers[register as usize] &= !BME280_SENSOR_MODE_MSK;
+                    }
+                }
+            }
+            BME280_CONFIG_ADDR => self.registers[register as usize] = payload,
+            // all other registers are read only
+            _ => {}
+        }
+    }
+
+    fn reset(&mut self) {
+        self.measuring = false;
+        self.registers[BME280_CTRL_HUM_ADDR as usize] = 0;
+        self.registers[BME280_CTRL_MEAS_ADDR as usize] = 0;
+        self.registers[BME280_CONFIG_ADDR as usize] = 0;
+        self.write_data(&RawMeasurements {
+            temperature: BME280_SKIPPED_TEMPERATURE,
+            pressure: BME280_SKIPPED_PRESSURE,
+            humidity: BME280_SKIPPED_HUMIDITY,
+        });
+    }
+
+    /// Converts the channels enabled in the control registers into the data registers
+    fn convert(&mut self) {
+        let ctrl_meas = self.registers[BME280_CTRL_MEAS_ADDR as usize];
+        let ctrl_hum = self.registers[BME280_CTRL_HUM_ADDR as usize];
+        let mut raw = self.raw_measurements();
+        if ctrl_meas & registers::CtrlMeas::OSRS_T_MASK == 0 {
+            raw.temperature = BME280_SKIPPED_TEMPERATURE;
+        }
+        if ctrl_meas & registers::CtrlMeas::OSRS_P_MASK == 0 {
+            raw.pressure = BME280_SKIPPED_PRESSURE;
+        }
+        if ctrl_hum & BME280_CTRL_HUM_MSK == 0 {
+            raw.humidity = BME280_SKIPPED_HUMIDITY;
+        }
+        self.write_data(&raw);
+    }
+
+    /// Converts again after a change of the environment when running in normal mode
+    fn refresh(&mut self) {
+        if self.normal_mode() {
+            self.convert();
+        }
+    }
+
+    fn write_data(&mut self, raw: &RawMeasurements) {
+        let data = BME280_DATA_ADDR as usize;
+        self.registers[data..data + 8].copy_from_slice(&[
+            (raw.pressure >> 12) as u8,
+            (raw.pressure >> 4) as u8,
+            (raw.pressure << 4) as u8,
+            (raw.temperature >> 12) as u8,
+            (raw.temperature >> 4) as u8,
+            (raw.temperature << 4) as u8,
+            (raw.humidity >> 8) as u8,
+            raw.humidity as u8,
+        ]);
+    }
+
+    fn handle(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), ErrorKind> {
+        if address != self.address {
+            return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+        }
+        for operation in operations {
+            match operation {
+                // writes are register and value pairs, a lone register sets the read address
+                Operation::Write(bytes) => {
+                    for pair in bytes.chunks(2) {
+                        match *pair {
+                            [register, payload] => self.write_register(register, payload),
+                            [register] => self.pointer = register,
+                            _ => {}
+                        }
+                    }
+                }
+                Operation::Read(buffer) => {
+                    for byte in buffer.iter_mut() {
+                        *byte = self.read_register(self.pointer);
+                        self.pointer = self.pointer.wrapping_add(1);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Finds the smallest raw value in `0..=max` whose output reaches `target`, for outputs rising
+/// with the raw value. Raw values without output count as below the target.
+fn invert(max: u32, target: i64, output: impl Fn(u32) -> Option<i64>) -> u32 {
+    let (mut low, mut high) = (0, max);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if output(mid).is_some_and(|value| value >= target) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    low
+}
+
+impl ErrorType for SimulatedBme280 {
+    type Error = ErrorKind;
+}
+
+#[cfg(feature = "sync")]
+impl embedded_hal::i2c::I2c for SimulatedBme280 {
+    fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.handle(address, operations)
+    }
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::i2c::I2c for SimulatedBme280 {
+    async fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.handle(address, operations)
+    }
+}
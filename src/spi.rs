@@ -2,6 +2,7 @@
 
 #[cfg(feature = "async")]
 use core::future::Future;
+use core::num::NonZeroU16;
 #[cfg(feature = "sync")]
 use embedded_hal::delay::DelayNs;
 use embedded_hal::spi::Operation;
@@ -11,19 +12,33 @@ use embedded_hal::spi::SpiDevice;
 use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
 #[cfg(feature = "async")]
 use embedded_hal_async::spi::SpiDevice as AsyncSpiDevice;
+#[cfg(all(feature = "async", feature = "float"))]
+use futures_util::{Stream, stream};
 
 #[cfg(feature = "async")]
 use super::{AsyncBME280Common, AsyncInterface};
 #[cfg(feature = "sync")]
 use super::{BME280Common, Interface};
 
+use super::clock::{Clock, Sample};
+use super::with_delay::WithDelay;
 use super::{
-    BME280_H_CALIB_DATA_LEN, BME280_P_T_CALIB_DATA_LEN, BME280_P_T_H_DATA_LEN, Configuration,
-    Error, IIRFilter, Measurements, MeasurementsFixedRaw, Oversampling,
+    BME280_H_CALIB_DATA_LEN, BME280_P_T_CALIB_DATA_LEN, BME280_P_T_DATA_LEN, BME280_P_T_H_DATA_LEN,
+    BME280_T_DATA_LEN, CalibrationData, ChipModel, Configuration, Error, IIRFilter,
+    MeasurementFormat, MeasurementsFixedRaw, Oversampling, RawMeasurements, RecoveryReport,
+    SensorMode, Status,
 };
 
+#[cfg(feature = "float")]
+use super::Measurements;
+#[cfg(feature = "double-precision")]
+use super::MeasurementsDouble;
 #[cfg(feature = "fixed")]
 use super::MeasurementsFixed;
+#[cfg(feature = "metrics")]
+use super::Metrics;
+#[cfg(feature = "float")]
+use super::clock::StampedMeasurements;
 
 /// Representation of a BME280
 #[maybe_async_cfg::maybe(
@@ -62,22 +77,166 @@ where
     // SPI::Buf: AsyncSpiBus<u8>,
 {
     /// Create a new BME280 struct
-    pub fn new(spi: SPI) -> Result<Self, Error<SPIError<SPIE>>> {
+    pub fn new(spi: SPI) -> Result<Self, Error<SPIE>> {
         Ok(Self {
-            common: AsyncBME280Common {
-                interface: AsyncSPIInterface { spi },
-                calibration: None,
-            },
+            common: AsyncBME280Common::new(AsyncSPIInterface {
+                spi,
+                three_wire: false,
+            }),
+        })
+    }
+
+    /// Create a new BME280 struct owning its delay, so that `init()` and `measure()` need no
+    /// delay argument
+    pub fn new_with_delay<D>(spi: SPI, delay: D) -> Result<WithDelay<Self, D>, Error<SPIE>> {
+        Ok(WithDelay::new(Self::new(spi)?, delay))
+    }
+
+    /// Create a new BME280 struct for a sensor wired in 3-wire SPI mode.
+    /// The sensor is switched to 3-wire mode during initialization, so the SPI device must
+    /// support half-duplex operation on the shared data line.
+    pub fn new_three_wire(spi: SPI) -> Result<Self, Error<SPIE>> {
+        Ok(Self {
+            common: AsyncBME280Common::new(AsyncSPIInterface {
+                spi,
+                three_wire: true,
+            }),
         })
     }
 
+    /// Consumes the driver and releases the SPI device, e.g. to hand the bus to another driver.
+    /// The sensor is left in its current mode.
+    pub fn into_inner(self) -> SPI {
+        self.common.interface.spi
+    }
+
+    /// Enables or disables write verification.
+    /// When enabled, every register write is read back and a mismatch is reported as
+    /// [`Error::WriteVerificationFailed`]. This costs an additional bus transaction per write.
+    pub fn set_write_verification(&mut self, enabled: bool) {
+        self.common.verify_writes = enabled;
+    }
+
+    /// Enables or disables plausibility validation of measurements.
+    /// When enabled, measurements holding the power-on reset values of the data registers or
+    /// falling outside the operating range of -40 to 85 deg C and 300 to 1100 hPa are reported as
+    /// [`Error::ImplausibleMeasurement`], e.g. after a bus glitch or an unexpected sensor reset.
+    /// Since the compensation clamps to these ranges, values at the range limits are rejected.
+    pub fn set_validation(&mut self, enabled: bool) {
+        self.common.validate = enabled;
+    }
+
+    /// Enables or disables lazy calibration, which must be set before `init()`.
+    /// When enabled, `init()` only resets and configures the sensor, and the calibration data is
+    /// read when the first conversion starts, e.g. by the first `measure()`. This shortens the
+    /// initialization and spreads the bus load when many sensors are brought up at once.
+    /// Calibration data read earlier is kept across later initializations.
+    pub fn set_lazy_calibration(&mut self, enabled: bool) {
+        self.common.lazy_calibration = enabled;
+    }
+
+    /// Enables automatic recovery after `failures` consecutive forced conversions failing with a
+    /// bus error or a timeout, or disables it with 0, the default.
+    /// Once the threshold is reached, [`Self::recover`] is called and the conversion repeated
+    /// once. Only the error of the recovery or of the repeated conversion is returned.
+    /// This covers `measure()`, the channel, averaged and median variants and `measure_raw()`,
+    /// in all formats. Reads without a conversion, such as `read_measurement()` and
+    /// `poll_measurement()`, are not recovered, and neither are implausible measurements.
+    pub fn set_auto_recovery(&mut self, failures: u8) {
+        self.common.recovery_threshold = failures;
+    }
+
+    /// Enables or disables frame comparison for `poll_measurement()`.
+    /// When enabled, a poll also returns a sample whose data registers differ from the previous
+    /// one although no conversion was seen running, so polling slower than the conversion time
+    /// still catches new samples. A missed conversion with exactly the same readings on all
+    /// channels is then not reported.
+    pub fn set_poll_frame_comparison(&mut self, enabled: bool) {
+        self.common.compare_frames = enabled;
+    }
+
+    /// Returns the fine resolution temperature (`t_fine`) of the last compensated measurement.
+    /// This is the intermediate value shared by the Bosch compensation formulas, and is `None`
+    /// until a measurement has been read.
+    pub fn t_fine(&self) -> Option<i32> {
+        self.common.t_fine
+    }
+
+    /// Lets [`Self::measure_pt`] and [`Self::measure_pt_fixed`] reuse the `t_fine` of the last
+    /// of these calls that converted the temperature, for up to `max_age_us` microseconds.
+    /// Reusing calls skip the temperature conversion, which speeds up tight pressure loops, and
+    /// return the temperature of that measurement. The age is measured with the `now_us`
+    /// timestamps passed to these calls, so a loop that stalls converts the temperature again.
+    /// Any other measurement converts the temperature, and the next pressure measurement then
+    /// converts it again, as the time of that conversion is unknown. A `max_age_us` of 0, the
+    /// default, disables the reuse.
+    pub fn set_t_fine_reuse(&mut self, max_age_us: u32) {
+        self.common.t_fine_max_age_us = max_age_us;
+    }
+
+    /// Returns the sequence number of the last measurement returned by the driver, or 0 before
+    /// the first one.
+    /// Every processed measurement increments it, so gaps and repeats downstream reveal dropped
+    /// and duplicated readings. It wraps around after `u32::MAX`.
+    pub fn sequence(&self) -> u32 {
+        self.common.sequence
+    }
+
+    /// Returns the longest time in microseconds a forced conversion takes with the configuration
+    /// applied by the driver
+    pub fn max_measurement_time_us(&self) -> u32 {
+        self.common.config.max_measurement_time_us()
+    }
+
+    /// Returns the bus and validation counters
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> Metrics {
+        self.common.metrics.metrics
+    }
+
+    /// Resets the bus and validation counters
+    #[cfg(feature = "metrics")]
+    pub fn reset_metrics(&mut self) {
+        self.common.metrics.metrics = Metrics::default();
+    }
+
+    /// Returns the calibration data read during initialization
+    pub fn calibration(&self) -> Option<&CalibrationData> {
+        self.common.calibration.as_ref()
+    }
+
+    /// Sets a temperature offset in hundredths of degrees celsius, e.g. -150 to compensate for
+    /// 1.50 deg C of self-heating.
+    /// The offset is applied during compensation, so pressure and humidity take it into account.
+    /// It is stored with the calibration data and kept across soft resets. Offsets beyond
+    /// ±125 deg C are saturated.
+    pub fn set_temperature_offset(&mut self, offset: i32) -> Result<(), Error<SPIE>> {
+        self.common.calibration_mut()?.temperature_offset = offset;
+        Ok(())
+    }
+
+    /// Sets a humidity correction, e.g. from a two-point salt solution calibration.
+    /// The offset is in hundredths of percent relative humidity and the gain is in Q16.16
+    /// format (65536 for 1.0). Compensated humidity is corrected as `humidity * gain + offset`.
+    /// The correction is stored with the calibration data and kept across soft resets.
+    pub fn set_humidity_correction(&mut self, offset: i32, gain: u32) -> Result<(), Error<SPIE>> {
+        let calibration = self.common.calibration_mut()?;
+        calibration.humidity_offset = offset;
+        calibration.humidity_gain = gain;
+        Ok(())
+    }
+
+    /// Sets a pressure offset in pascals that is added to every compensated pressure.
+    /// The offset is stored with the calibration data and kept across soft resets.
+    pub fn set_pressure_offset(&mut self, offset: i32) -> Result<(), Error<SPIE>> {
+        self.common.calibration_mut()?.pressure_offset = offset;
+        Ok(())
+    }
+
     /// Initializes the BME280.
     /// This configures 2x temperature oversampling, 16x pressure oversampling, and the IIR filter
     /// coefficient 16.
-    pub async fn init<D: AsyncDelayNs>(
-        &mut self,
-        delay: &mut D,
-    ) -> Result<(), Error<SPIError<SPIE>>> {
+    pub async fn init<D: AsyncDelayNs>(&mut self, delay: &mut D) -> Result<(), Error<SPIE>> {
         self.common
             .init(
                 delay,
@@ -95,16 +254,58 @@ where
         &mut self,
         delay: &mut D,
         config: Configuration,
-    ) -> Result<(), Error<SPIError<SPIE>>> {
+    ) -> Result<(), Error<SPIE>> {
         self.common.init(delay, config).await
     }
 
+    /// Initializes the BME280 with calibration data captured earlier, applying the given
+    /// configuration.
+    /// This skips reading the calibration data from the sensor, e.g. to shorten wakeup after a
+    /// deep sleep. The calibration data must come from the same sensor, see [`Self::calibration`].
+    pub async fn init_with_calibration<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+        config: Configuration,
+        calibration: CalibrationData,
+    ) -> Result<(), Error<SPIE>> {
+        self.common
+            .init_with_calibration(delay, config, calibration)
+            .await
+    }
+
+    /// Performs a soft reset of the sensor and applies the given configuration.
+    /// The calibration data read during initialization is kept.
+    pub async fn soft_reset<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+        config: Configuration,
+    ) -> Result<(), Error<SPIE>> {
+        self.common.reset(delay, config).await
+    }
+
+    /// Changes the humidity oversampling setting at runtime.
+    /// This takes care of the register write sequence required by the sensor and keeps the current
+    /// power mode, so it can be used while the sensor is running in normal mode.
+    pub async fn set_humidity_oversampling(
+        &mut self,
+        oversampling: Oversampling,
+    ) -> Result<(), Error<SPIE>> {
+        self.common.set_humidity_oversampling(oversampling).await
+    }
+
+    /// Reads back the configuration currently held by the sensor.
+    /// This can be compared against the applied configuration to detect unexpected resets.
+    pub async fn configuration(&mut self) -> Result<Configuration, Error<SPIE>> {
+        self.common.configuration().await
+    }
+
     /// Captures and processes sensor data for temperature, pressure, and humidity
+    #[cfg(feature = "float")]
     pub async fn measure<D: AsyncDelayNs>(
         &mut self,
         delay: &mut D,
-    ) -> Result<Measurements<SPIError<SPIE>>, Error<SPIError<SPIE>>> {
-        self.common.measure(delay).await
+    ) -> Result<Measurements<SPIE>, Error<SPIE>> {
+        self.common.measure_as(delay).await
     }
 
     /// Captures and processes sensor data for temperature, pressure, and humidity in fixed point format
@@ -112,16 +313,384 @@ where
     pub async fn measure_fixed<D: AsyncDelayNs>(
         &mut self,
         delay: &mut D,
-    ) -> Result<MeasurementsFixed<SPIError<SPIE>>, Error<SPIError<SPIE>>> {
-        self.common.measure_fixed(delay).await
+    ) -> Result<MeasurementsFixed<SPIE>, Error<SPIE>> {
+        self.common.measure_as(delay).await
+    }
+
+    /// Captures and processes sensor data for temperature, pressure, and humidity using the double
+    /// precision compensation formulas
+    #[cfg(feature = "double-precision")]
+    pub async fn measure_double<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<MeasurementsDouble<SPIE>, Error<SPIE>> {
+        self.common.measure_as(delay).await
     }
 
-    /// Captures and processes sensor data for temperature, pressure, and humidity in raw fixed point format
+    /// Captures and processes sensor data for temperature, pressure, and humidity in raw fixed point format.
+    /// Temperature is reported in 0.01 °C, pressure in Q24.8 Pa, and humidity in Q22.10 %RH, as
+    /// computed by the integer compensation formulas of the datasheet.
+    /// With the `pressure-32bit` feature, pressure uses the 32-bit only formula and has a
+    /// resolution of 1 Pa.
     pub async fn measure_fixed_raw<D: AsyncDelayNs>(
         &mut self,
         delay: &mut D,
-    ) -> Result<MeasurementsFixedRaw<SPIError<SPIE>>, Error<SPIError<SPIE>>> {
-        self.common.measure_fixed_raw(delay).await
+    ) -> Result<MeasurementsFixedRaw<SPIE>, Error<SPIE>> {
+        self.common.measure_as(delay).await
+    }
+
+    /// Captures sensor data for temperature, pressure, and humidity without compensation.
+    /// The raw values can be compensated later, e.g. on a host, with [`crate::compensate`] and
+    /// the data returned by [`Self::calibration`].
+    pub async fn measure_raw<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<RawMeasurements, Error<SPIE>> {
+        self.common.measure_raw(delay).await
+    }
+
+    /// Captures and processes only the temperature, in degrees celsius.
+    /// Pressure and humidity are not converted and only the temperature data registers are read,
+    /// which makes this faster than a full measurement. The configured oversampling is restored
+    /// by the next full measurement.
+    #[cfg(feature = "float")]
+    pub async fn measure_temperature<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<f32, Error<SPIE>> {
+        let measurements: Measurements<_> = self.common.measure_temperature_as(delay).await?;
+        Ok(measurements.temperature)
+    }
+
+    /// Captures and processes only the temperature, in hundredths of degrees celsius.
+    /// See [`Self::measure_temperature`].
+    pub async fn measure_temperature_fixed<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<i32, Error<SPIE>> {
+        let measurements: MeasurementsFixedRaw<_> =
+            self.common.measure_temperature_as(delay).await?;
+        Ok(measurements.temperature)
+    }
+
+    /// Captures and processes pressure and temperature, skipping the humidity conversion.
+    /// Only the pressure and temperature data registers are read, which saves time and power
+    /// when humidity is not needed. The humidity of the result is always `None`, and the
+    /// configured humidity oversampling is restored by the next full measurement.
+    /// `now_us` is the current time in microseconds of any monotonic time base, which ages the
+    /// temperature reused with [`Self::set_t_fine_reuse`]; it is ignored without reuse.
+    #[cfg(feature = "float")]
+    pub async fn measure_pt<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+        now_us: u64,
+    ) -> Result<Measurements<SPIE>, Error<SPIE>> {
+        self.common.measure_pt_as(delay, now_us).await
+    }
+
+    /// Captures and processes pressure and temperature in raw fixed point format, skipping the
+    /// humidity conversion.
+    /// See [`Self::measure_pt`].
+    pub async fn measure_pt_fixed<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+        now_us: u64,
+    ) -> Result<MeasurementsFixedRaw<SPIE>, Error<SPIE>> {
+        self.common.measure_pt_as(delay, now_us).await
+    }
+
+    /// Captures and processes sensor data for temperature, pressure, and humidity in the format
+    /// chosen by the caller, e.g. `bme280.measure_as::<MeasurementsFixedRaw<_>, _>(&mut delay)`.
+    pub async fn measure_as<M: MeasurementFormat<SPIE>, D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<M, Error<SPIE>> {
+        self.common.measure_as(delay).await
+    }
+
+    /// Captures and processes sensor data for temperature, pressure, and humidity, stamped with
+    /// the time of `clock` once the measurement is read
+    #[cfg(feature = "float")]
+    pub async fn measure_stamped<C: Clock, D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+        clock: &mut C,
+    ) -> Result<StampedMeasurements<C, SPIE>, Error<SPIE>> {
+        self.measure_stamped_as(delay, clock).await
+    }
+
+    /// Captures and processes sensor data in the format chosen by the caller, stamped with the
+    /// time of `clock` once the measurement is read
+    pub async fn measure_stamped_as<M: MeasurementFormat<SPIE>, C: Clock, D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+        clock: &mut C,
+    ) -> Result<Sample<C::Instant, M>, Error<SPIE>> {
+        let measurements = self.common.measure_as(delay).await?;
+        Ok(Sample::new(clock.now(), self.common.sequence, measurements))
+    }
+
+    /// Captures the given number of forced measurements back to back and returns their mean.
+    /// The raw values are averaged before compensation, which reduces noise beyond what the
+    /// oversampling settings of the sensor provide, at the cost of a proportionally longer
+    /// measurement. Skipped channels stay skipped.
+    #[cfg(feature = "float")]
+    pub async fn measure_averaged<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+        samples: NonZeroU16,
+    ) -> Result<Measurements<SPIE>, Error<SPIE>> {
+        self.common.measure_averaged_as(delay, samples).await
+    }
+
+    /// Captures the given number of forced measurements back to back and returns their mean in
+    /// the format chosen by the caller. See [`Self::measure_averaged`].
+    pub async fn measure_averaged_as<M: MeasurementFormat<SPIE>, D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+        samples: NonZeroU16,
+    ) -> Result<M, Error<SPIE>> {
+        self.common.measure_averaged_as(delay, samples).await
+    }
+
+    /// Captures `N` forced measurements back to back and returns the median of each channel,
+    /// e.g. `bme280.measure_median::<5, _>(&mut delay)`. `N` must be odd, which is checked at
+    /// compile time. The median of the raw values is taken before compensation, which removes
+    /// single-sample spikes such as those caused by bus glitches.
+    #[cfg(feature = "float")]
+    pub async fn measure_median<const N: usize, D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Measurements<SPIE>, Error<SPIE>> {
+        self.common.measure_median_as::<N, _, _>(delay).await
+    }
+
+    /// Captures `N` forced measurements back to back and returns the median of each channel in
+    /// the format chosen by the caller. See [`Self::measure_median`].
+    pub async fn measure_median_as<const N: usize, M: MeasurementFormat<SPIE>, D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<M, Error<SPIE>> {
+        self.common.measure_median_as::<N, _, _>(delay).await
+    }
+
+    /// Triggers a single conversion in forced mode and returns without waiting for it to finish.
+    /// Call [`Self::read_measurement`] once the conversion time has elapsed to retrieve the
+    /// result.
+    pub async fn trigger_measurement(&mut self) -> Result<(), Error<SPIE>> {
+        self.common.forced().await
+    }
+
+    /// Puts the sensor into normal mode, where conversions run continuously with the configured
+    /// standby time in between.
+    /// Use [`Self::read_measurement`] to retrieve the latest sample.
+    pub async fn start_normal_mode(&mut self) -> Result<(), Error<SPIE>> {
+        self.common.normal().await
+    }
+
+    /// Reads and processes the most recent sensor data for temperature, pressure, and humidity
+    /// without triggering a new conversion.
+    /// In normal mode, this returns the latest sample converted by the sensor. In forced mode,
+    /// this returns the result of the conversion started by [`Self::trigger_measurement`].
+    #[cfg(feature = "float")]
+    pub async fn read_measurement(&mut self) -> Result<Measurements<SPIE>, Error<SPIE>> {
+        self.common.read_measurement_as().await
+    }
+
+    /// Reads and processes the most recent sensor data in fixed point format without triggering
+    /// a new conversion
+    #[cfg(feature = "fixed")]
+    pub async fn read_measurement_fixed(&mut self) -> Result<MeasurementsFixed<SPIE>, Error<SPIE>> {
+        self.common.read_measurement_as().await
+    }
+
+    /// Reads and processes the most recent sensor data in double precision without triggering a
+    /// new conversion
+    #[cfg(feature = "double-precision")]
+    pub async fn read_measurement_double(
+        &mut self,
+    ) -> Result<MeasurementsDouble<SPIE>, Error<SPIE>> {
+        self.common.read_measurement_as().await
+    }
+
+    /// Reads and processes the most recent sensor data in raw fixed point format without
+    /// triggering a new conversion
+    pub async fn read_measurement_fixed_raw(
+        &mut self,
+    ) -> Result<MeasurementsFixedRaw<SPIE>, Error<SPIE>> {
+        self.common.read_measurement_as().await
+    }
+
+    /// Reads the most recent sensor data without compensation or triggering a new conversion
+    pub async fn read_measurement_raw(&mut self) -> Result<RawMeasurements, Error<SPIE>> {
+        self.common.read_measurement_raw().await
+    }
+
+    /// Polls for a new sample in normal mode, reading the status and data registers in a single
+    /// burst instead of separate status and data reads.
+    /// Returns `None` while a conversion is running, and afterwards until the next conversion
+    /// was seen running. Polling must therefore be faster than the conversion time, see
+    /// [`Self::max_measurement_time_us`], unless [`Self::set_poll_frame_comparison`] is enabled.
+    /// The first poll after a change of the configuration or the mode returns the current data.
+    #[cfg(feature = "float")]
+    pub async fn poll_measurement(&mut self) -> Result<Option<Measurements<SPIE>>, Error<SPIE>> {
+        self.common.poll_measurement_as().await
+    }
+
+    /// Polls for a new sample in normal mode in the format chosen by the caller, see
+    /// `poll_measurement()`
+    pub async fn poll_measurement_as<M: MeasurementFormat<SPIE>>(
+        &mut self,
+    ) -> Result<Option<M>, Error<SPIE>> {
+        self.common.poll_measurement_as().await
+    }
+
+    /// Reads the most recent sensor data into the first [`registers::DATA_LEN`](crate::registers::DATA_LEN)
+    /// bytes of a caller-provided buffer, without compensation or triggering a new conversion.
+    /// This lets DMA-backed bus implementations read straight into their own buffer, which is
+    /// then processed with [`Self::process_data_as`]. A shorter buffer is rejected with
+    /// [`Error::InvalidData`].
+    pub async fn read_data_into(&mut self, buffer: &mut [u8]) -> Result<(), Error<SPIE>> {
+        self.common.read_data_into(buffer).await
+    }
+
+    /// Processes sensor data read with [`Self::read_data_into`] in the format chosen by the
+    /// caller. A buffer shorter than [`registers::DATA_LEN`](crate::registers::DATA_LEN) bytes is
+    /// rejected with [`Error::InvalidData`].
+    pub fn process_data_as<M: MeasurementFormat<SPIE>>(
+        &mut self,
+        buffer: &[u8],
+    ) -> Result<M, Error<SPIE>> {
+        self.common.process_data_as(buffer)
+    }
+
+    /// Reads and processes the most recent sensor data in the format chosen by the caller
+    /// without triggering a new conversion
+    pub async fn read_measurement_as<M: MeasurementFormat<SPIE>>(
+        &mut self,
+    ) -> Result<M, Error<SPIE>> {
+        self.common.read_measurement_as().await
+    }
+
+    /// Reads the chip ID register to detect which sensor variant is attached.
+    /// This returns [`Error::UnsupportedChip`] if the chip ID is not recognized.
+    pub async fn chip_model(&mut self) -> Result<ChipModel, Error<SPIE>> {
+        self.common.chip_model().await
+    }
+
+    /// Reads the factory calibration coefficients from the sensor.
+    /// This does not change the calibration data used by the driver, see [`Self::calibration`].
+    pub async fn read_calibration(&mut self) -> Result<CalibrationData, Error<SPIE>> {
+        self.common.read_calibration().await
+    }
+
+    /// Reads the status register, e.g. to poll whether a conversion is still running
+    pub async fn status(&mut self) -> Result<Status, Error<SPIE>> {
+        self.common.status().await
+    }
+
+    /// Waits until the running conversion has completed, backing off between polls.
+    /// After [`Self::trigger_measurement`], this polls the mode until the sensor is back in sleep
+    /// mode. In normal mode, this waits for the next conversion to complete.
+    /// This returns [`Error::Timeout`] if the conversion does not complete in time.
+    pub async fn wait_for_data_ready<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<SPIE>> {
+        self.common.wait_for_data_ready(delay).await
+    }
+
+    /// Reads the current power mode of the sensor.
+    /// A sensor in forced mode is still converting and returns to sleep mode once done.
+    pub async fn current_mode(&mut self) -> Result<SensorMode, Error<SPIE>> {
+        self.common.mode().await
+    }
+
+    /// Puts the sensor into sleep mode to save power.
+    /// Calibration data is kept, so the sensor does not need to be initialized again.
+    pub async fn sleep(&mut self) -> Result<(), Error<SPIE>> {
+        self.common.sleep().await
+    }
+
+    /// Wakes the sensor from sleep mode, resuming normal mode if it was active when
+    /// [`Self::sleep`] was called.
+    /// Forced measurements wake the sensor on their own and need no explicit wake up.
+    pub async fn wake(&mut self) -> Result<(), Error<SPIE>> {
+        self.common.wake().await
+    }
+
+    /// Recovers from persistent bus or protocol errors, e.g. after a brown-out of the sensor.
+    /// This soft resets the sensor, checks its chip ID, re-applies the configuration and
+    /// restarts normal mode if the driver had started it and no reset or sleep stopped it
+    /// since. The calibration data read during initialization is reused, so only a driver that
+    /// never read it reads it again.
+    /// Automatic recovery, see [`Self::set_auto_recovery`], only runs on forced conversions, so
+    /// only explicit calls resume normal mode.
+    pub async fn recover<D: AsyncDelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<RecoveryReport, Error<SPIE>> {
+        self.common.recover(delay).await
+    }
+}
+
+#[cfg(all(feature = "sync", feature = "float"))]
+impl<SPI, SPIE> BME280<SPI>
+where
+    SPI: SpiDevice<Error = SPIE>,
+{
+    /// Returns an endless iterator of measurements, triggering a forced conversion every
+    /// `period_ms` milliseconds, starting immediately.
+    /// The conversion time is deducted from the wait between measurements, so the period holds
+    /// as long as it is longer than the conversion. Errors are yielded without ending the
+    /// iteration.
+    pub fn samples<'a, D: DelayNs>(
+        &'a mut self,
+        delay: &'a mut D,
+        period_ms: u32,
+    ) -> impl Iterator<Item = Result<Measurements<SPIE>, Error<SPIE>>> + 'a {
+        let period_us = period_ms.saturating_mul(1000);
+        let mut first = true;
+        core::iter::from_fn(move || {
+            if !first {
+                let conversion_us = self.common.config.max_measurement_time_us();
+                delay.delay_us(period_us.saturating_sub(conversion_us));
+            }
+            first = false;
+            Some(self.measure(delay))
+        })
+    }
+}
+
+#[cfg(all(feature = "async", feature = "float"))]
+impl<SPI, SPIE> AsyncBME280<SPI>
+where
+    SPI: AsyncSpiDevice<Error = SPIE>,
+{
+    /// Returns a stream of measurements, triggering a forced conversion every `period_ms`
+    /// milliseconds, starting immediately.
+    /// The conversion time is deducted from the wait between measurements, so the period holds
+    /// as long as it is longer than the conversion. Errors are yielded without ending the
+    /// stream.
+    pub fn measurement_stream<'a, D: AsyncDelayNs>(
+        &'a mut self,
+        delay: &'a mut D,
+        period_ms: u32,
+    ) -> impl Stream<Item = Result<Measurements<SPIE>, Error<SPIE>>> + 'a {
+        let period_us = period_ms.saturating_mul(1000);
+        stream::unfold(
+            (self, delay, true),
+            move |(bme280, delay, first)| async move {
+                if !first {
+                    let conversion_us = bme280.common.config.max_measurement_time_us();
+                    delay
+                        .delay_us(period_us.saturating_sub(conversion_us))
+                        .await;
+                }
+                let measurements = bme280.measure(delay).await;
+                Some((measurements, (bme280, delay, false)))
+            },
+        )
     }
 }
 
@@ -134,6 +703,8 @@ where
 struct AsyncSPIInterface<SPI> {
     /// concrete SPI device implementation
     spi: SPI,
+    /// whether the sensor is wired in 3-wire mode
+    three_wire: bool,
 }
 
 #[cfg(feature = "sync")]
@@ -142,7 +713,7 @@ where
     SPI: SpiDevice,
     // SPI::Buf: SpiBus<u8>,
 {
-    type Error = SPIError<SPI::Error>;
+    type Error = SPI::Error;
 
     fn read_register(&mut self, register: u8) -> Result<u8, Error<Self::Error>> {
         let mut result = [0u8];
@@ -159,6 +730,24 @@ where
         Ok(data)
     }
 
+    fn read_pt_data(
+        &mut self,
+        register: u8,
+    ) -> Result<[u8; BME280_P_T_DATA_LEN], Error<Self::Error>> {
+        let mut data = [0; BME280_P_T_DATA_LEN];
+        self.read_any_register(register, &mut data)?;
+        Ok(data)
+    }
+
+    fn read_temperature_data(
+        &mut self,
+        register: u8,
+    ) -> Result<[u8; BME280_T_DATA_LEN], Error<Self::Error>> {
+        let mut data = [0; BME280_T_DATA_LEN];
+        self.read_any_register(register, &mut data)?;
+        Ok(data)
+    }
+
     fn read_pt_calib_data(
         &mut self,
         register: u8,
@@ -177,14 +766,20 @@ where
         Ok(data)
     }
 
+    fn read_into(&mut self, register: u8, data: &mut [u8]) -> Result<(), Error<Self::Error>> {
+        self.read_any_register(register, data)
+    }
+
     fn write_register(&mut self, register: u8, payload: u8) -> Result<(), Error<Self::Error>> {
         // If the first bit is 0, the register is written.
         let data = [register & 0x7f, payload];
-        self.spi
-            .write(&data)
-            .map_err(|e| Error::Bus(SPIError::SPI(e)))?;
+        self.spi.write(&data).map_err(Error::Bus)?;
         Ok(())
     }
+
+    fn spi3w_en(&self) -> bool {
+        self.three_wire
+    }
 }
 
 #[cfg(feature = "async")]
@@ -193,7 +788,7 @@ where
     SPI: AsyncSpiDevice,
     // SPI::Buf: AsyncSpiBus<u8>,
 {
-    type Error = SPIError<SPI::Error>;
+    type Error = SPI::Error;
 
     type ReadRegisterFuture<'a>
         = impl Future<Output = Result<u8, Error<Self::Error>>>
@@ -219,6 +814,30 @@ where
         }
     }
 
+    type ReadPtDataFuture<'a>
+        = impl Future<Output = Result<[u8; BME280_P_T_DATA_LEN], Error<Self::Error>>>
+    where
+        SPI: 'a;
+    fn read_pt_data(&mut self, register: u8) -> Self::ReadPtDataFuture<'_> {
+        async move {
+            let mut data = [0; BME280_P_T_DATA_LEN];
+            self.read_any_register(register, &mut data).await?;
+            Ok(data)
+        }
+    }
+
+    type ReadTemperatureDataFuture<'a>
+        = impl Future<Output = Result<[u8; BME280_T_DATA_LEN], Error<Self::Error>>>
+    where
+        SPI: 'a;
+    fn read_temperature_data(&mut self, register: u8) -> Self::ReadTemperatureDataFuture<'_> {
+        async move {
+            let mut data = [0; BME280_T_DATA_LEN];
+            self.read_any_register(register, &mut data).await?;
+            Ok(data)
+        }
+    }
+
     type ReadPtCalibDataFuture<'a>
         = impl Future<Output = Result<[u8; BME280_P_T_CALIB_DATA_LEN], Error<Self::Error>>>
     where
@@ -243,6 +862,14 @@ where
         }
     }
 
+    type ReadIntoFuture<'a>
+        = impl Future<Output = Result<(), Error<Self::Error>>>
+    where
+        SPI: 'a;
+    fn read_into<'a>(&'a mut self, register: u8, data: &'a mut [u8]) -> Self::ReadIntoFuture<'a> {
+        self.read_any_register(register, data)
+    }
+
     type WriteRegisterFuture<'a>
         = impl Future<Output = Result<(), Error<Self::Error>>>
     where
@@ -250,14 +877,15 @@ where
     fn write_register(&mut self, register: u8, payload: u8) -> Self::WriteRegisterFuture<'_> {
         async move {
             // If the first bit is 0, the register is written.
-            let transfer = [register & 0x7f, payload];
-            self.spi
-                .transfer(&mut [], &transfer)
-                .await
-                .map_err(|e| Error::Bus(SPIError::SPI(e)))?;
+            let data = [register & 0x7f, payload];
+            self.spi.write(&data).await.map_err(Error::Bus)?;
             Ok(())
         }
     }
+
+    fn spi3w_en(&self) -> bool {
+        self.three_wire
+    }
 }
 
 #[maybe_async_cfg::maybe(
@@ -277,18 +905,11 @@ where
         &mut self,
         register: u8,
         data: &mut [u8],
-    ) -> Result<(), Error<SPIError<SPI::Error>>> {
+    ) -> Result<(), Error<SPI::Error>> {
         self.spi
             .transaction(&mut [Operation::Write(&[register]), Operation::Read(data)])
             .await
-            .map_err(|e| Error::Bus(SPIError::SPI(e)))?;
+            .map_err(Error::Bus)?;
         Ok(())
     }
 }
-
-/// Error which occurred during an SPI transaction
-#[derive(Clone, Copy, Debug)]
-pub enum SPIError<SPIE> {
-    /// The SPI implementation returned an error
-    SPI(SPIE),
-}
@@ -0,0 +1,114 @@
+//! Driver split into a control handle and a read handle, e.g. to give the configuration rights
+//! to a supervisor task and the measurement rights to a sampling task:
+//!
+//! ```ignore
+//! let bme280 = RefCell::new(BME280::new_primary(i2c));
+//! let (mut control, mut reader) = bme280.split();
+//! control.init(&mut delay)?;
+//! let measurements = reader.measure(&mut delay)?;
+//! ```
+//!
+//! Both handles lock the shared driver for the duration of each call, so a measurement never
+//! interleaves with a reconfiguration. A [`RefCell`] shares the driver within a single context;
+//! with the `with_std` feature, a `std::sync::Mutex` shares it between threads.
+
+use core::cell::RefCell;
+use core::marker::PhantomData;
+
+use embedded_hal::delay::DelayNs;
+
+use crate::forward::forward_methods;
+
+/// Container giving exclusive access to a shared driver `B`
+pub trait Share<B> {
+    /// Runs `f` with exclusive access to the driver
+    fn lock<R>(&self, f: impl FnOnce(&mut B) -> R) -> R;
+
+    /// Splits the shared driver into a control handle and a read handle
+    fn split(&self) -> (Control<'_, Self, B>, Reader<'_, Self, B>)
+    where
+        Self: Sized,
+    {
+        (
+            Control {
+                shared: self,
+                _driver: PhantomData,
+            },
+            Reader {
+                shared: self,
+                _driver: PhantomData,
+            },
+        )
+    }
+}
+
+/// Panics if the driver is already borrowed, i.e. when a handle is used from within a call of
+/// the other handle.
+impl<B> Share<B> for RefCell<B> {
+    fn lock<R>(&self, f: impl FnOnce(&mut B) -> R) -> R {
+        f(&mut self.borrow_mut())
+    }
+}
+
+/// The driver stays usable after a panic of a previous holder of the lock.
+#[cfg(feature = "with_std")]
+impl<B> Share<B> for std::sync::Mutex<B> {
+    fn lock<R>(&self, f: impl FnOnce(&mut B) -> R) -> R {
+        f(&mut self
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner))
+    }
+}
+
+/// Handle initializing, reconfiguring and resetting a shared driver
+#[derive(Debug)]
+pub struct Control<'a, S, B> {
+    shared: &'a S,
+    _driver: PhantomData<fn(&mut B)>,
+}
+
+/// Handle taking measurements with a shared driver
+#[derive(Debug)]
+pub struct Reader<'a, S, B> {
+    shared: &'a S,
+    _driver: PhantomData<fn(&mut B)>,
+}
+
+/// Runs the driver methods with the shared driver locked
+macro_rules! locked {
+    ($this:ident, $delay:ident, $method:ident($($arg:expr),*)) => {
+        $this.shared.lock(|bme280| bme280.$method($delay $(, $arg)*))
+    };
+    ($this:ident, $method:ident($($arg:expr),*)) => {
+        $this.shared.lock(|bme280| bme280.$method($($arg),*))
+    };
+}
+
+macro_rules! impl_split {
+    ($driver:ty, $bus:ident: $bus_trait:path, $error:ty) => {
+        forward_methods! {
+            impl[$bus: $bus_trait, S: Share<$driver>] Control<'_, S, $driver> where [];
+            receiver: [&mut], async: [], delay: [D: DelayNs], format: [], error: $error,
+            call: locked;
+            setup, control
+        }
+
+        forward_methods! {
+            impl[$bus: $bus_trait, S: Share<$driver>] Reader<'_, S, $driver> where [];
+            receiver: [&mut], async: [], delay: [D: DelayNs], format: [], error: $error,
+            call: locked;
+            measure, read
+        }
+    };
+}
+
+impl_split!(
+    crate::i2c::BME280<I2C>,
+    I2C: embedded_hal::i2c::I2c,
+    I2C::Error
+);
+impl_split!(
+    crate::spi::BME280<SPI>,
+    SPI: embedded_hal::spi::SpiDevice,
+    SPI::Error
+);
@@ -0,0 +1,140 @@
+//! Statistics over a window of recent measurements.
+
+use crate::Measurements;
+use crate::math;
+
+/// Fixed-capacity window over the last `N` values of a single channel.
+/// Once full, each new value replaces the oldest one.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Window<const N: usize> {
+    values: [f32; N],
+    len: usize,
+    next: usize,
+}
+
+impl<const N: usize> Default for Window<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Window<N> {
+    /// Creates an empty window
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "the window must hold at least one value") };
+        Self {
+            values: [0.0; N],
+            len: 0,
+            next: 0,
+        }
+    }
+
+    /// Adds a value, replacing the oldest one if the window is full
+    pub fn push(&mut self, value: f32) {
+        self.values[self.next] = value;
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+    }
+
+    /// Removes all values
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.next = 0;
+    }
+
+    /// Returns the number of values in the window
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the window holds no values
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns whether the window holds `N` values
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the values in the window, in no particular order
+    pub fn values(&self) -> &[f32] {
+        &self.values[..self.len]
+    }
+
+    /// Returns the smallest value, or `None` if the window is empty
+    pub fn min(&self) -> Option<f32> {
+        self.values().iter().copied().reduce(f32::min)
+    }
+
+    /// Returns the largest value, or `None` if the window is empty
+    pub fn max(&self) -> Option<f32> {
+        self.values().iter().copied().reduce(f32::max)
+    }
+
+    /// Returns the arithmetic mean, or `None` if the window is empty
+    pub fn mean(&self) -> Option<f32> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.values().iter().sum::<f32>() / self.len as f32)
+    }
+
+    /// Returns the sample standard deviation, or `None` with fewer than two values
+    pub fn std_dev(&self) -> Option<f32> {
+        if self.len < 2 {
+            return None;
+        }
+        let mean = self.mean()?;
+        let sum_of_squares: f32 = self
+            .values()
+            .iter()
+            .map(|value| (value - mean) * (value - mean))
+            .sum();
+        Some(math::sqrtf(sum_of_squares / (self.len - 1) as f32))
+    }
+}
+
+/// Per-channel windows over the last `N` measurements, e.g. for a data logger reporting the
+/// minimum, maximum, mean and standard deviation of each logging interval.
+/// Skipped pressure and humidity values are not added to their windows.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Statistics<const N: usize> {
+    /// temperatures in degrees celsius
+    pub temperature: Window<N>,
+    /// pressures in pascals
+    pub pressure: Window<N>,
+    /// percent relative humidities
+    pub humidity: Window<N>,
+}
+
+impl<const N: usize> Statistics<N> {
+    /// Creates empty windows
+    pub const fn new() -> Self {
+        Self {
+            temperature: Window::new(),
+            pressure: Window::new(),
+            humidity: Window::new(),
+        }
+    }
+
+    /// Adds a measurement to the windows
+    pub fn push<E>(&mut self, measurements: &Measurements<E>) {
+        self.temperature.push(measurements.temperature);
+        if let Some(pressure) = measurements.pressure {
+            self.pressure.push(pressure);
+        }
+        if let Some(humidity) = measurements.humidity {
+            self.humidity.push(humidity);
+        }
+    }
+
+    /// Removes all values from the windows
+    pub fn clear(&mut self) {
+        self.temperature.clear();
+        self.pressure.clear();
+        self.humidity.clear();
+    }
+}
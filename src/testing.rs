@@ -0,0 +1,83 @@
+//! Ready-made [`embedded-hal-mock`](embedded_hal_mock) expectations for unit tests of code
+//! using the I²C driver, so they do not have to reproduce the register traffic by hand:
+//!
+//! ```ignore
+//! let address = 0x76;
+//! let mut expectations = init_transactions(address);
+//! expectations.extend(measure_transactions(address, DATA));
+//! let mut i2c = Mock::new(&expectations);
+//!
+//! let mut bme280 = BME280::new_primary(i2c.clone());
+//! bme280.init(&mut NoopDelay)?;
+//! let measurements = bme280.measure_fixed_raw(&mut NoopDelay)?;
+//! assert_eq!(measurements.temperature, DATA_TEMPERATURE);
+//! i2c.done();
+//! ```
+//!
+//! The sequences match a BME280 in sleep mode after power on, as expected by [`Mock::done`].
+//!
+//! [`Mock::done`]: embedded_hal_mock::eh1::i2c::Mock::done
+
+use embedded_hal_mock::eh1::i2c::Transaction;
+
+use crate::{
+    BME280_CHIP_ID, BME280_CHIP_ID_ADDR, BME280_CONFIG_ADDR, BME280_CTRL_HUM_ADDR,
+    BME280_CTRL_MEAS_ADDR, BME280_DATA_ADDR, BME280_FORCED_MODE, BME280_H_CALIB_DATA_ADDR,
+    BME280_P_T_CALIB_DATA_ADDR, BME280_P_T_H_DATA_LEN, BME280_PWR_CTRL_ADDR, BME280_RESET_ADDR,
+    BME280_SENSOR_MODE_MSK, BME280_SLEEP_MODE, BME280_SOFT_RESET_CMD, Configuration,
+    ShadowRegisters,
+};
+
+pub use crate::simulator::{CALIBRATION_H, CALIBRATION_PT};
+
+/// Data registers, the 8 bytes starting at 0xF7, holding the raw values of the compensation
+/// example in the datasheet and a raw humidity of 27000
+pub const DATA: [u8; BME280_P_T_H_DATA_LEN] = [0x65, 0x5A, 0xC0, 0x7E, 0xED, 0x00, 0x69, 0x78];
+
+/// Temperature in hundredths of degrees celsius compensated from [`DATA`]
+pub const DATA_TEMPERATURE: i32 = 2508;
+/// Pressure in Q24.8 pascals compensated from [`DATA`], about 1006.53 hPa, without the
+/// `pressure-32bit` feature
+pub const DATA_PRESSURE: u32 = 25767233;
+/// Relative humidity in Q22.10 percent compensated from [`DATA`], about 38.27 %
+pub const DATA_HUMIDITY: u32 = 39190;
+
+/// Registers written by `init()`, which applies the indoor navigation settings
+fn init_registers() -> ShadowRegisters {
+    ShadowRegisters::new(&Configuration::indoor_navigation(), false)
+}
+
+/// Transactions of `BME280::init()` on a BME280 at `address`
+pub fn init_transactions(address: u8) -> Vec<Transaction> {
+    let registers = init_registers();
+    vec![
+        Transaction::write_read(address, vec![BME280_CHIP_ID_ADDR], vec![BME280_CHIP_ID]),
+        Transaction::write(address, vec![BME280_RESET_ADDR, BME280_SOFT_RESET_CMD]),
+        Transaction::write_read(
+            address,
+            vec![BME280_P_T_CALIB_DATA_ADDR],
+            CALIBRATION_PT.to_vec(),
+        ),
+        Transaction::write_read(
+            address,
+            vec![BME280_H_CALIB_DATA_ADDR],
+            CALIBRATION_H.to_vec(),
+        ),
+        Transaction::write_read(address, vec![BME280_PWR_CTRL_ADDR], vec![BME280_SLEEP_MODE]),
+        Transaction::write(address, vec![BME280_CTRL_HUM_ADDR, registers.ctrl_hum]),
+        Transaction::write(address, vec![BME280_CTRL_MEAS_ADDR, registers.ctrl_meas]),
+        Transaction::write(address, vec![BME280_CONFIG_ADDR, registers.config]),
+    ]
+}
+
+/// Transactions of a forced measurement, e.g. `BME280::measure()`, following
+/// [`init_transactions`], with the sensor returning the given data registers
+pub fn measure_transactions(address: u8, data: [u8; BME280_P_T_H_DATA_LEN]) -> Vec<Transaction> {
+    let ctrl_meas = init_registers().ctrl_meas;
+    let forced = (ctrl_meas & !BME280_SENSOR_MODE_MSK) | BME280_FORCED_MODE;
+    vec![
+        Transaction::write_read(address, vec![BME280_PWR_CTRL_ADDR], vec![ctrl_meas]),
+        Transaction::write(address, vec![BME280_CTRL_MEAS_ADDR, forced]),
+        Transaction::write_read(address, vec![BME280_DATA_ADDR], data.to_vec()),
+    ]
+}
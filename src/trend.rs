@@ -0,0 +1,199 @@
+//! Barometric pressure tendency over a rolling three hour window.
+//!
+//! The application feeds [`PressureTrend`] with pressures at its own cadence, together with a
+//! monotonic timestamp in seconds, and queries the [`Tendency`] classified after the thresholds
+//! used in weather reports.
+
+/// Length of the tendency window in seconds
+pub const TENDENCY_WINDOW_S: u32 = 3 * 60 * 60;
+/// Shortest span of samples in seconds from which a tendency is extrapolated
+pub const MIN_TENDENCY_SPAN_S: u32 = 60 * 60;
+
+/// Pressure tendency, classified by the pressure change over three hours
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Tendency {
+    /// less than 0.1 hPa change
+    Steady,
+    /// 0.1 to 1.5 hPa rise
+    RisingSlowly,
+    /// 1.5 to 3.5 hPa rise
+    Rising,
+    /// 3.5 to 6.0 hPa rise
+    RisingQuickly,
+    /// more than 6.0 hPa rise
+    RisingVeryRapidly,
+    /// 0.1 to 1.5 hPa fall
+    FallingSlowly,
+    /// 1.5 to 3.5 hPa fall
+    Falling,
+    /// 3.5 to 6.0 hPa fall
+    FallingQuickly,
+    /// more than 6.0 hPa fall
+    FallingVeryRapidly,
+}
+
+impl Tendency {
+    /// Classifies a pressure change over three hours in pascals
+    pub fn from_change(change_pa: i32) -> Self {
+        let rising = change_pa > 0;
+        match change_pa.unsigned_abs() {
+            0..10 => Tendency::Steady,
+            10..=150 if rising => Tendency::RisingSlowly,
+            10..=150 => Tendency::FallingSlowly,
+            151..=350 if rising => Tendency::Rising,
+            151..=350 => Tendency::Falling,
+            351..=600 if rising => Tendency::RisingQuickly,
+            351..=600 => Tendency::FallingQuickly,
+            _ if rising => Tendency::RisingVeryRapidly,
+            _ => Tendency::FallingVeryRapidly,
+        }
+    }
+
+    /// Returns whether the pressure is rising
+    pub fn is_rising(&self) -> bool {
+        matches!(
+            self,
+            Tendency::RisingSlowly
+                | Tendency::Rising
+                | Tendency::RisingQuickly
+                | Tendency::RisingVeryRapidly
+        )
+    }
+
+    /// Returns whether the pressure is falling
+    pub fn is_falling(&self) -> bool {
+        matches!(
+            self,
+            Tendency::FallingSlowly
+                | Tendency::Falling
+                | Tendency::FallingQuickly
+                | Tendency::FallingVeryRapidly
+        )
+    }
+
+    /// Returns whether the pressure changes by more than 3.5 hPa in three hours.
+    /// A rapid fall is the classic precursor of a storm.
+    pub fn is_rapid(&self) -> bool {
+        matches!(
+            self,
+            Tendency::RisingQuickly
+                | Tendency::RisingVeryRapidly
+                | Tendency::FallingQuickly
+                | Tendency::FallingVeryRapidly
+        )
+    }
+}
+
+/// Pressure sample fed to [`PressureTrend`]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct Sample {
+    timestamp_s: u32,
+    pressure_pa: u32,
+}
+
+/// Rolling window over the pressures of the last three hours.
+/// `N` must be large enough to hold three hours of samples at the cadence of the application,
+/// e.g. 19 samples at one sample every ten minutes; when full, the oldest sample is dropped.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PressureTrend<const N: usize> {
+    samples: [Sample; N],
+    /// index of the oldest sample
+    start: usize,
+    len: usize,
+}
+
+impl<const N: usize> Default for PressureTrend<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> PressureTrend<N> {
+    /// Creates an empty window
+    pub const fn new() -> Self {
+        const { assert!(N >= 2, "the window must hold at least two samples") };
+        Self {
+            samples: [Sample {
+                timestamp_s: 0,
+                pressure_pa: 0,
+            }; N],
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Adds a pressure in pascals measured at `timestamp_s`, a monotonic timestamp in seconds
+    /// which may wrap around.
+    /// Samples older than three hours are dropped.
+    pub fn push(&mut self, timestamp_s: u32, pressure_pa: u32) {
+        if self.len == N {
+            self.start = (self.start + 1) % N;
+            self.len -= 1;
+        }
+        self.samples[(self.start + self.len) % N] = Sample {
+            timestamp_s,
+            pressure_pa,
+        };
+        self.len += 1;
+
+        while timestamp_s.wrapping_sub(self.oldest().timestamp_s) > TENDENCY_WINDOW_S {
+            self.start = (self.start + 1) % N;
+            self.len -= 1;
+        }
+    }
+
+    /// Removes all samples
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.len = 0;
+    }
+
+    /// Returns the number of samples in the window
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the window holds no samples
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the time in seconds between the oldest and the newest sample
+    pub fn span_s(&self) -> u32 {
+        if self.is_empty() {
+            return 0;
+        }
+        self.newest()
+            .timestamp_s
+            .wrapping_sub(self.oldest().timestamp_s)
+    }
+
+    /// Returns the pressure change in pascals over three hours.
+    /// When the samples span less than three hours, the change is extrapolated linearly;
+    /// this returns `None` until the samples span at least [`MIN_TENDENCY_SPAN_S`].
+    pub fn change_pa(&self) -> Option<i32> {
+        let span = self.span_s();
+        if span < MIN_TENDENCY_SPAN_S {
+            return None;
+        }
+        let change = i64::from(self.newest().pressure_pa) - i64::from(self.oldest().pressure_pa);
+        let change = change * i64::from(TENDENCY_WINDOW_S) / i64::from(span);
+        Some(change.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32)
+    }
+
+    /// Classifies the pressure change over three hours, see [`Self::change_pa`]
+    pub fn tendency(&self) -> Option<Tendency> {
+        self.change_pa().map(Tendency::from_change)
+    }
+
+    fn oldest(&self) -> &Sample {
+        &self.samples[self.start]
+    }
+
+    fn newest(&self) -> &Sample {
+        &self.samples[(self.start + self.len - 1) % N]
+    }
+}
@@ -0,0 +1,108 @@
+//! Drivers owning their delay, so it does not need to be passed to every call:
+//!
+//! ```ignore
+//! let mut bme280 = BME280::new_with_delay(i2c, 0x76, delay);
+//! bme280.init()?;
+//! let measurements = bme280.measure()?;
+//! ```
+
+use crate::forward::forward_methods;
+
+/// Driver bundled with the delay used for all waits.
+/// Driver methods not forwarded here are reachable through [`Self::inner`].
+#[derive(Debug, Default)]
+pub struct WithDelay<B, D> {
+    bme280: B,
+    delay: D,
+}
+
+impl<B, D> WithDelay<B, D> {
+    /// Bundles a driver with a delay
+    pub fn new(bme280: B, delay: D) -> Self {
+        Self { bme280, delay }
+    }
+
+    /// Returns the wrapped driver
+    pub fn inner(&mut self) -> &mut B {
+        &mut self.bme280
+    }
+
+    /// Releases the wrapped driver and the delay
+    pub fn into_inner(self) -> (B, D) {
+        (self.bme280, self.delay)
+    }
+}
+
+/// Passes the owned delay to the driver methods that wait
+#[cfg(feature = "sync")]
+macro_rules! with_delay {
+    ($this:ident, $delay:ident, $method:ident($($arg:expr),*)) => {
+        $this.bme280.$method(&mut $this.delay $(, $arg)*)
+    };
+    ($this:ident, $method:ident($($arg:expr),*)) => {
+        $this.bme280.$method($($arg),*)
+    };
+}
+
+/// Passes the owned delay to the async driver methods that wait
+#[cfg(feature = "async")]
+macro_rules! with_delay_async {
+    ($this:ident, $delay:ident, $method:ident($($arg:expr),*)) => {
+        $this.bme280.$method(&mut $this.delay $(, $arg)*).await
+    };
+    ($this:ident, $method:ident($($arg:expr),*)) => {
+        $this.bme280.$method($($arg),*).await
+    };
+}
+
+macro_rules! impl_with_delay {
+    ($driver:ty, $bus:ident: $bus_trait:path, $delay_trait:path, $error:ty, $call:ident $(, $async:tt)?) => {
+        forward_methods! {
+            impl[$bus: $bus_trait, D: $delay_trait] WithDelay<$driver, D> where [];
+            receiver: [&mut], async: [$($async)?], delay: [], format: [], error: $error, call: $call;
+            setup, measure, control, read
+        }
+
+        impl<$bus: $bus_trait, D: $delay_trait> WithDelay<$driver, D> {
+            /// Consumes the driver and releases the bus device and the delay
+            pub fn release(self) -> ($bus, D) {
+                (self.bme280.into_inner(), self.delay)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "sync")]
+impl_with_delay!(
+    crate::i2c::BME280<I2C>,
+    I2C: embedded_hal::i2c::I2c,
+    embedded_hal::delay::DelayNs,
+    I2C::Error,
+    with_delay
+);
+#[cfg(feature = "sync")]
+impl_with_delay!(
+    crate::spi::BME280<SPI>,
+    SPI: embedded_hal::spi::SpiDevice,
+    embedded_hal::delay::DelayNs,
+    SPI::Error,
+    with_delay
+);
+#[cfg(feature = "async")]
+impl_with_delay!(
+    crate::i2c::AsyncBME280<I2C>,
+    I2C: embedded_hal_async::i2c::I2c,
+    embedded_hal_async::delay::DelayNs,
+    I2C::Error,
+    with_delay_async,
+    async
+);
+#[cfg(feature = "async")]
+impl_with_delay!(
+    crate::spi::AsyncBME280<SPI>,
+    SPI: embedded_hal_async::spi::SpiDevice,
+    embedded_hal_async::delay::DelayNs,
+    SPI::Error,
+    with_delay_async,
+    async
+);
@@ -0,0 +1,60 @@
+//! Builds `tests/no_float_firmware` without the `float` feature for a Cortex-M0 target, which has
+//! no FPU, and checks that the linked binary contains no soft-float routines.
+//! The test is skipped if the `thumbv6m-none-eabi` target is not installed.
+
+use std::path::Path;
+use std::process::Command;
+
+const TARGET: &str = "thumbv6m-none-eabi";
+
+/// Symbol name fragments of the soft-float routines of `compiler_builtins` and of `libm`
+const SOFT_FLOAT_SYMBOLS: &[&str] = &[
+    "__aeabi_f",
+    "__aeabi_d",
+    "compiler_builtins5float",
+    "libm4math",
+];
+
+fn target_installed() -> bool {
+    let Ok(output) = Command::new("rustc").args(["--print", "sysroot"]).output() else {
+        return false;
+    };
+    let sysroot = String::from_utf8_lossy(&output.stdout);
+    Path::new(sysroot.trim())
+        .join("lib/rustlib")
+        .join(TARGET)
+        .exists()
+}
+
+#[test]
+fn no_soft_float_routines_without_float_feature() {
+    if !target_installed() {
+        eprintln!("skipped: the {TARGET} target is not installed");
+        return;
+    }
+
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/no_float_firmware/Cargo.toml");
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("no_float_firmware");
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--release", "--target", TARGET])
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success(), "building the firmware failed");
+
+    let binary = target_dir
+        .join(TARGET)
+        .join("release/bme280-no-float-firmware");
+    let binary = std::fs::read(binary).expect("failed to read the firmware");
+    for symbol in SOFT_FLOAT_SYMBOLS {
+        assert!(
+            !binary
+                .windows(symbol.len())
+                .any(|window| window == symbol.as_bytes()),
+            "the firmware contains the soft-float symbol {symbol}"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Firmware stub for a target without an FPU, built by `tests/no_float.rs` to check that the
+//! driver pulls in no soft-float routines when the `float` feature is disabled.
+
+#![no_std]
+#![no_main]
+
+use bme280::i2c::BME280;
+use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::{ErrorKind, ErrorType, I2c, Operation, SevenBitAddress};
+
+struct Bus;
+
+impl ErrorType for Bus {
+    type Error = ErrorKind;
+}
+
+impl I2c for Bus {
+    fn transaction(
+        &mut self,
+        _address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        for operation in operations {
+            if let Operation::Read(buffer) = operation {
+                buffer.fill(0x60);
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Delay;
+
+impl DelayNs for Delay {
+    fn delay_ns(&mut self, _ns: u32) {}
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn _start() -> ! {
+    let mut bme280 = BME280::new_primary(Bus);
+    let _ = bme280.init(&mut Delay);
+    loop {
+        let _ = core::hint::black_box(bme280.measure_fixed_raw(&mut Delay));
+    }
+}
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    loop {}
+}